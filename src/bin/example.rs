@@ -3,7 +3,7 @@ use hyprland::dispatch::*;
 use hyprland::event_listener::EventListenerMutable as EventListener;
 use hyprland::keyword::*;
 use hyprland::prelude::*;
-use hyprland::shared::WorkspaceType;
+use hyprland::shared::{MonitorRef, WorkspaceType};
 
 fn main() -> hyprland::shared::HResult<()> {
     // We can call dispatchers with the dispatch macro, and struct!
@@ -61,8 +61,8 @@ fn main() -> hyprland::shared::HResult<()> {
     event_listener.add_active_monitor_change_handler(|data, state| {
         let hyprland::event_listener::MonitorEventData(monitor, _) = data;
 
-        if monitor == *"DP-1".to_string() {
-            state.active_monitor = "eDP-1".to_string()
+        if monitor == MonitorRef::from("DP-1") {
+            state.active_monitor = MonitorRef::from("eDP-1")
         }
     });
 
@@ -7,6 +7,7 @@ use crate::shared::*;
 /// Module providing stuff for adding an removing keybinds
 pub mod binds {
     use super::*;
+    pub use crate::dispatch::{Mod, SubmapName};
 
     trait Join: IntoIterator {
         fn join(&self) -> String;
@@ -39,30 +40,6 @@ pub mod binds {
         }
     }
 
-    #[derive(Debug, Clone, Copy)]
-    #[allow(missing_docs)]
-    /// Enum for mod keys used in bind combinations
-    pub enum Mod {
-        SUPER,
-        SHIFT,
-        ALT,
-        CTRL,
-        NONE,
-    }
-
-    impl ToString for Mod {
-        fn to_string(&self) -> String {
-            match self {
-                Mod::NONE => "",
-                Mod::SUPER => "SUPER",
-                Mod::SHIFT => "SHIFT",
-                Mod::ALT => "ALT",
-                Mod::CTRL => "CTRL",
-            }
-            .to_string()
-        }
-    }
-
     impl Join for Vec<Mod> {
         fn join(&self) -> String {
             let mut buf = String::new();
@@ -87,6 +64,7 @@ pub mod binds {
         r,
     }
 
+    #[allow(clippy::to_string_trait_impl)]
     impl ToString for Flag {
         fn to_string(&self) -> String {
             match self {
@@ -152,6 +130,87 @@ pub mod binds {
             Ok(())
         }
     }
+    /// Struct to define a submap (a set of modal keybinds) and its binds
+    ///
+    /// ```rust, no_run
+    /// # use hyprland::shared::HResult;
+    /// # fn main() -> HResult<()> {
+    /// use hyprland::config::binds::*;
+    /// use hyprland::dispatch::{DispatchType, Direction, Position};
+    /// Submap::new("resize")
+    ///     .bind(Binding {
+    ///         mods: vec![],
+    ///         key: Key::Key("l"),
+    ///         flags: vec![Flag::e],
+    ///         dispatcher: DispatchType::ResizeActive(Position::Delta(10, 0)),
+    ///     })
+    ///     .reset_bind(vec![], Key::Key("escape"))
+    ///     .define()?;
+    /// // enter it with a regular bind
+    /// Binder::bind(Binding {
+    ///     mods: vec![Mod::SUPER],
+    ///     key: Key::Key("r"),
+    ///     flags: vec![],
+    ///     dispatcher: DispatchType::Submap(SubmapName::Name("resize")),
+    /// })
+    /// # }
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct Submap<'a> {
+        /// The name of the submap
+        pub name: &'a str,
+        /// The binds in the submap
+        pub binds: Vec<Binding<'a>>,
+    }
+
+    impl<'a> Submap<'a> {
+        /// Creates a new submap without any binds
+        pub fn new(name: &'a str) -> Self {
+            Self {
+                name,
+                binds: vec![],
+            }
+        }
+        /// Adds a bind to the submap
+        pub fn bind(mut self, binding: Binding<'a>) -> Self {
+            self.binds.push(binding);
+            self
+        }
+        /// Adds a bind that leaves the submap
+        pub fn reset_bind(self, mods: Vec<Mod>, key: Key<'a>) -> Self {
+            self.bind(Binding {
+                mods,
+                key,
+                flags: vec![],
+                dispatcher: DispatchType::Submap(SubmapName::Reset),
+            })
+        }
+        /// Defines the submap and its binds
+        ///
+        /// The submap is always reset, even if a bind fails, so later binds aren't added to it
+        pub fn define(self) -> HResult<()> {
+            Keyword::set("submap", self.name)?;
+            let bound = self.binds.into_iter().try_for_each(Binder::bind);
+            let reset = Keyword::set("submap", "reset");
+            bound.and(reset)
+        }
+        /// Defines the submap and its binds (async)
+        ///
+        /// The submap is always reset, even if a bind fails, so later binds aren't added to it
+        pub async fn define_async(self) -> HResult<()> {
+            Keyword::set_async("submap", self.name).await?;
+            let mut bound = Ok(());
+            for binding in self.binds {
+                bound = Binder::bind_async(binding).await;
+                if bound.is_err() {
+                    break;
+                }
+            }
+            let reset = Keyword::set_async("submap", "reset").await;
+            bound.and(reset)
+        }
+    }
+
     /// Very macro basic abstraction over [Binder] for internal use, **Dont use this instead use [crate::bind]**
     #[macro_export]
     #[doc(hidden)]
@@ -267,3 +326,30 @@ fn test_binds() {
     };
     assert_eq!(built_bind, "SUPER,v,togglefloating");
 }
+
+#[test]
+fn test_submap_define_resets_on_error() {
+    use binds::*;
+    let recorder = Recorder::start();
+    recorder
+        .respond_with("ok")
+        .respond_with("Invalid dispatcher, requested \"nope\" does not exist");
+    let result = Submap::new("resize")
+        .bind(Binding {
+            mods: vec![],
+            key: Key::Key("l"),
+            flags: vec![],
+            dispatcher: DispatchType::Exec("nope"),
+        })
+        .reset_bind(vec![], Key::Key("escape"))
+        .define();
+    assert!(result.is_err());
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "keyword submap resize",
+            "keyword bind ,l,exec,nope",
+            "keyword submap reset"
+        ]
+    );
+}
@@ -4,13 +4,23 @@ macro_rules! impl_on {
         impl HyprData for $name {
             fn get() -> HResult<Self> {
                 let data = call_hyprctl_data_cmd(DataCommands::$name);
-                let deserialized: $name = serde_json::from_str(&data)?;
-                Ok(deserialized)
+                parse_data(DataCommands::$name.as_str(), data)
             }
             async fn get_async() -> HResult<Self> {
                 let data = call_hyprctl_data_cmd_async(DataCommands::$name).await;
-                let deserialized: $name = serde_json::from_str(&data)?;
-                Ok(deserialized)
+                parse_data(DataCommands::$name.as_str(), data)
+            }
+        }
+
+        #[async_trait]
+        impl HyprDataWithReport for $name {
+            fn get_with_report() -> HResult<(Self, SchemaReport)> {
+                let data = call_hyprctl_data_cmd(DataCommands::$name);
+                parse_data_with_report(DataCommands::$name.as_str(), data)
+            }
+            async fn get_with_report_async() -> HResult<(Self, SchemaReport)> {
+                let data = call_hyprctl_data_cmd_async(DataCommands::$name).await;
+                parse_data_with_report(DataCommands::$name.as_str(), data)
             }
         }
     };
@@ -29,22 +39,48 @@ macro_rules! create_data_struct {
         impl HyprData for $name {
             fn get() -> HResult<Self> {
                 let data = call_hyprctl_data_cmd($kind);
-                let deserialized: Vec<$held> = serde_json::from_str(&data)?;
                 Ok(Self {
-                    held: deserialized,
+                    held: parse_data($kind.as_str(), data)?,
                     pos: 0,
                 })
             }
             async fn get_async() -> HResult<Self> {
                 let data = call_hyprctl_data_cmd_async($kind).await;
-                let deserialized: Vec<$held> = serde_json::from_str(&data)?;
                 Ok(Self {
-                    held: deserialized,
+                    held: parse_data($kind.as_str(), data)?,
                     pos: 0,
                 })
             }
         }
 
+        #[async_trait]
+        impl HyprDataWithReport for $name {
+            fn get_with_report() -> HResult<(Self, SchemaReport)> {
+                let data = call_hyprctl_data_cmd($kind);
+                let (deserialized, report): (Vec<$held>, _) =
+                    parse_data_with_report($kind.as_str(), data)?;
+                Ok((
+                    Self {
+                        held: deserialized,
+                        pos: 0,
+                    },
+                    report,
+                ))
+            }
+            async fn get_with_report_async() -> HResult<(Self, SchemaReport)> {
+                let data = call_hyprctl_data_cmd_async($kind).await;
+                let (deserialized, report): (Vec<$held>, _) =
+                    parse_data_with_report($kind.as_str(), data)?;
+                Ok((
+                    Self {
+                        held: deserialized,
+                        pos: 0,
+                    },
+                    report,
+                ))
+            }
+        }
+
         impl Iterator for $name {
             type Item = $held;
 
@@ -68,19 +104,34 @@ macro_rules! create_data_struct {
     (sing $name:ident,$kind:path,$held:ty,$c:literal) => {
         #[doc = $c]
         #[derive(Debug)]
+        #[allow(dead_code)]
         pub struct $name($held);
 
         #[async_trait]
         impl HyprData for $name {
             fn get() -> HResult<Self> {
                 let data = call_hyprctl_data_cmd($kind);
-                let deserialized: $held = serde_json::from_str(&data)?;
-                Ok(Self(deserialized))
+                Ok(Self(parse_data($kind.as_str(), data)?))
             }
             async fn get_async() -> HResult<Self> {
                 let data = call_hyprctl_data_cmd_async($kind).await;
-                let deserialized: $held = serde_json::from_str(&data)?;
-                Ok(Self(deserialized))
+                Ok(Self(parse_data($kind.as_str(), data)?))
+            }
+        }
+
+        #[async_trait]
+        impl HyprDataWithReport for $name {
+            fn get_with_report() -> HResult<(Self, SchemaReport)> {
+                let data = call_hyprctl_data_cmd($kind);
+                let (deserialized, report): ($held, _) =
+                    parse_data_with_report($kind.as_str(), data)?;
+                Ok((Self(deserialized), report))
+            }
+            async fn get_with_report_async() -> HResult<(Self, SchemaReport)> {
+                let data = call_hyprctl_data_cmd_async($kind).await;
+                let (deserialized, report): ($held, _) =
+                    parse_data_with_report($kind.as_str(), data)?;
+                Ok((Self(deserialized), report))
             }
         }
     };
@@ -37,6 +37,13 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Schema drift
+//!
+//! Hyprland often adds or renames fields, by default unknown fields are ignored and missing
+//! fields are an error. Use [set_deserialize_mode] to switch to [DeserializeMode::Lenient]
+//! (missing fields are filled with defaults) or [DeserializeMode::Strict] (any drift is an error),
+//! and [HyprDataWithReport] to get the [SchemaReport] of a query
 
 #[macro_use]
 mod macros;
@@ -46,6 +53,11 @@ use std::collections::HashMap;
 
 mod regular;
 
+/// Schema drift detection, and the lenient/strict deserialization modes
+mod schema;
+
+pub use crate::data::schema::*;
+
 /// Helpers data commands, these use other hyprctl commands to create new ones!
 mod helpers;
 
@@ -4,42 +4,18 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// This private function is to call socket commands
 async fn call_hyprctl_data_cmd_async(cmd: DataCommands) -> String {
-    let cmd_string = match cmd {
-        DataCommands::Monitors => "monitors",
-        DataCommands::ActiveWindow => "activewindow",
-        DataCommands::Clients => "clients",
-        DataCommands::Devices => "devices",
-        DataCommands::Layers => "layers",
-        DataCommands::Workspaces => "workspaces",
-        DataCommands::Version => "version",
-        DataCommands::CursorPosition => "cursorpos",
-    }
-    .to_string();
-
     let socket_path = get_socket_path(SocketType::Command);
 
-    match write_to_socket(socket_path, format!("j/{cmd_string}").as_bytes()).await {
+    match write_to_socket(socket_path, format!("j/{}", cmd.as_str()).as_bytes()).await {
         Ok(data) => data,
         Err(e) => panic!("A error occured while parsing the output from the hypr socket: {e:?}"),
     }
 }
 
 fn call_hyprctl_data_cmd(cmd: DataCommands) -> String {
-    let cmd_string = match cmd {
-        DataCommands::Monitors => "monitors",
-        DataCommands::ActiveWindow => "activewindow",
-        DataCommands::Clients => "clients",
-        DataCommands::Devices => "devices",
-        DataCommands::Layers => "layers",
-        DataCommands::Workspaces => "workspaces",
-        DataCommands::Version => "version",
-        DataCommands::CursorPosition => "cursorpos",
-    }
-    .to_string();
-
     let socket_path = get_socket_path(SocketType::Command);
 
-    match write_to_socket_sync(socket_path, format!("j/{cmd_string}").as_bytes()) {
+    match write_to_socket_sync(socket_path, format!("j/{}", cmd.as_str()).as_bytes()) {
         Ok(data) => data,
         Err(e) => panic!("A error occured while parsing the output from the hypr socket: {e:?}"),
     }
@@ -50,6 +26,7 @@ fn call_hyprctl_data_cmd(cmd: DataCommands) -> String {
 pub(crate) enum DataCommands {
     Monitors,
     Workspaces,
+    ActiveWorkspace,
     Clients,
     ActiveWindow,
     Layers,
@@ -58,8 +35,26 @@ pub(crate) enum DataCommands {
     CursorPosition,
 }
 
+impl DataCommands {
+    /// This method returns the command sent to the socket
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            DataCommands::Monitors => "monitors",
+            DataCommands::ActiveWindow => "activewindow",
+            DataCommands::Clients => "clients",
+            DataCommands::Devices => "devices",
+            DataCommands::Layers => "layers",
+            DataCommands::Workspaces => "workspaces",
+            DataCommands::ActiveWorkspace => "activeworkspace",
+            DataCommands::Version => "version",
+            DataCommands::CursorPosition => "cursorpos",
+        }
+    }
+}
+
 /// This struct holds a basic identifier for a workspace often used in other structs
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct WorkspaceBasic {
     /// The workspace Id
     pub id: WorkspaceId,
@@ -67,11 +62,18 @@ pub struct WorkspaceBasic {
     pub name: String,
 }
 
+impl From<&WorkspaceBasic> for WorkspaceRef {
+    fn from(work: &WorkspaceBasic) -> Self {
+        WorkspaceRef::from_parts(work.id, &work.name)
+    }
+}
+
 /// This enum provides the different monitor transforms
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Default)]
 #[repr(u8)]
 pub enum Transforms {
     /// No transform
+    #[default]
     Normal = 0,
     /// Rotated 90 degrees
     Normal90 = 1,
@@ -90,10 +92,11 @@ pub enum Transforms {
 }
 
 /// This struct holds information for a monitor
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Monitor {
     /// The monitor id
-    pub id: u8,
+    pub id: MonitorId,
     /// The monitor's name
     pub name: String,
     /// The monitor's description
@@ -125,6 +128,58 @@ pub struct Monitor {
     pub dpms_status: bool,
 }
 
+impl Monitor {
+    /// This method returns true if the [MonitorRef] refers to this monitor
+    pub fn matches(&self, monitor: &MonitorRef) -> bool {
+        match monitor {
+            MonitorRef::Id(id) => self.id == *id,
+            MonitorRef::Name(name) => self.name == *name,
+        }
+    }
+    /// This method returns the area the monitor covers in the layout,
+    /// taking its scale and transform into account
+    pub fn geometry(&self) -> Geometry {
+        let scale = if self.scale > 0.0 { self.scale } else { 1.0 };
+        let width = (f32::from(self.width) / scale).round() as i32;
+        let height = (f32::from(self.height) / scale).round() as i32;
+        let rotated = matches!(
+            self.transform,
+            Transforms::Normal90
+                | Transforms::Normal270
+                | Transforms::Flipped90
+                | Transforms::Flipped270
+        );
+        if rotated {
+            Geometry::new(self.x, self.y, height, width)
+        } else {
+            Geometry::new(self.x, self.y, width, height)
+        }
+    }
+    /// This method returns the area of the monitor not reserved by layer surfaces (eg. bars)
+    pub fn usable_area(&self) -> Geometry {
+        let (left, top, right, bottom) = self.reserved;
+        self.geometry()
+            .inset(left.into(), top.into(), right.into(), bottom.into())
+    }
+}
+
+impl From<&Monitor> for MonitorRef {
+    fn from(monitor: &Monitor) -> Self {
+        MonitorRef::Name(monitor.name.clone())
+    }
+}
+
+impl MonitorRef {
+    /// This method gets the monitor the reference refers to, if it exists
+    pub fn resolve(&self) -> HResult<Option<Monitor>> {
+        Ok(Monitors::get()?.find(|mon| mon.matches(self)))
+    }
+    /// This method gets the monitor the reference refers to, if it exists (async)
+    pub async fn resolve_async(&self) -> HResult<Option<Monitor>> {
+        Ok(Monitors::get_async().await?.find(|mon| mon.matches(self)))
+    }
+}
+
 #[async_trait]
 impl HyprDataActive for Monitor {
     fn get_active() -> HResult<Self> {
@@ -153,7 +208,8 @@ create_data_struct!(
 );
 
 /// This struct holds information for a workspace
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Workspace {
     /// The workspace Id
     pub id: WorkspaceId,
@@ -174,9 +230,19 @@ pub struct Workspace {
     pub last_window_title: String,
 }
 
+impl From<&Workspace> for WorkspaceRef {
+    fn from(work: &Workspace) -> Self {
+        WorkspaceRef::from_parts(work.id, &work.name)
+    }
+}
+
 #[async_trait]
 impl HyprDataActive for Workspace {
     fn get_active() -> HResult<Self> {
+        if Capabilities::current().active_workspace_query {
+            let data = call_hyprctl_data_cmd(DataCommands::ActiveWorkspace);
+            return parse_data(DataCommands::ActiveWorkspace.as_str(), data);
+        }
         let mut all = Workspaces::get()?;
         let mon = Monitor::get_active()?;
 
@@ -187,6 +253,10 @@ impl HyprDataActive for Workspace {
         }
     }
     async fn get_active_async() -> HResult<Self> {
+        if Capabilities::current().active_workspace_query {
+            let data = call_hyprctl_data_cmd_async(DataCommands::ActiveWorkspace).await;
+            return parse_data(DataCommands::ActiveWorkspace.as_str(), data);
+        }
         let mut all = Workspaces::get_async().await?;
         let mon = Monitor::get_active_async().await?;
 
@@ -206,7 +276,8 @@ create_data_struct!(
 );
 
 /// This struct holds information for a client/window
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Client {
     /// The client's [`Address`][crate::shared::Address]
     pub address: Address,
@@ -224,7 +295,7 @@ pub struct Client {
     #[serde(rename = "fullscreenMode")]
     pub fullscreen_mode: u8,
     /// The monitor the window is on
-    pub monitor: u8,
+    pub monitor: MonitorId,
     /// The window class
     pub class: String,
     /// The window title
@@ -239,10 +310,37 @@ pub struct Client {
     pub grouped: Vec<Box<Self>>,
     /// The swallowed window
     pub swallowing: Option<Box<Self>>,
+    /// The position of the window in the focus history (0 being the active window),
+    /// [None] on Hyprland versions that don't report it
+    #[serde(rename = "focusHistoryID")]
+    pub focus_history_id: Option<i32>,
+    /// The tags of the window, [None] on Hyprland versions without tags
+    /// (see [Capabilities::window_tags])
+    pub tags: Option<Vec<String>>,
+}
+
+impl Client {
+    /// This method returns a [MonitorRef] to the monitor the window is on
+    pub fn monitor_ref(&self) -> MonitorRef {
+        MonitorRef::Id(self.monitor)
+    }
+    /// This method returns a [WorkspaceRef] to the workspace the window is on
+    pub fn workspace_ref(&self) -> WorkspaceRef {
+        WorkspaceRef::from(&self.workspace)
+    }
+    /// This method returns the area the window covers in the layout
+    pub fn geometry(&self) -> Geometry {
+        Geometry::new(
+            self.at.0.into(),
+            self.at.1.into(),
+            self.size.0.into(),
+            self.size.1.into(),
+        )
+    }
 }
 
 /// This enum holds the information for the active window
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub(crate) struct ActiveWindow(
     /// The client data
     #[serde(deserialize_with = "object_empty_as_none")]
@@ -253,12 +351,12 @@ pub(crate) struct ActiveWindow(
 impl HyprDataActiveOptional for Client {
     fn get_active() -> HResult<Option<Self>> {
         let data = call_hyprctl_data_cmd(DataCommands::ActiveWindow);
-        let deserialized: ActiveWindow = serde_json::from_str(&data)?;
+        let deserialized: ActiveWindow = parse_data(DataCommands::ActiveWindow.as_str(), data)?;
         Ok(deserialized.0)
     }
     async fn get_active_async() -> HResult<Option<Self>> {
         let data = call_hyprctl_data_cmd_async(DataCommands::ActiveWindow).await;
-        let deserialized: ActiveWindow = serde_json::from_str(&data)?;
+        let deserialized: ActiveWindow = parse_data(DataCommands::ActiveWindow.as_str(), data)?;
         Ok(deserialized.0)
     }
 }
@@ -271,7 +369,8 @@ create_data_struct!(
 );
 
 /// This struct holds information about a layer surface/client
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct LayerClient {
     /// The layer's [`Address`][crate::shared::Address]
     pub address: Address,
@@ -288,7 +387,8 @@ pub struct LayerClient {
 }
 
 /// This struct holds all the layer surfaces for a display
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct LayerDisplay {
     /// The different levels of layers
     pub levels: HashMap<String, Vec<LayerClient>>,
@@ -302,7 +402,8 @@ create_data_struct!(
 );
 
 /// This struct holds information about a mouse device
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Mouse {
     /// The mouse's address
     pub address: Address,
@@ -311,7 +412,8 @@ pub struct Mouse {
 }
 
 /// This struct holds information about a keyboard device
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Keyboard {
     /// The keyboard's address
     pub address: Address,
@@ -357,7 +459,8 @@ pub enum TabletBelongsTo {
 }
 
 /// This struct holds information about a tablet device
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Tablet {
     /// The tablet's address
     pub address: Address,
@@ -372,7 +475,8 @@ pub struct Tablet {
 }
 
 /// This struct holds all current devices
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Devices {
     /// All the mice
     pub mice: Vec<Mouse>,
@@ -384,7 +488,8 @@ pub struct Devices {
 impl_on!(Devices);
 
 /// This struct holds version information
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Version {
     /// The git branch Hyprland was built on
     pub branch: String,
@@ -394,13 +499,46 @@ pub struct Version {
     pub dirty: bool,
     /// The git commit message
     pub commit_message: String,
+    /// The date of the git commit Hyprland was built on
+    pub commit_date: Option<String>,
+    /// The git tag Hyprland was built on (eg. `v0.34.0-73-gabc1234`)
+    pub tag: Option<String>,
     /// The flags that Hyprland was built with
     pub flags: Vec<String>,
 }
 impl_on!(Version);
 
+impl Version {
+    /// This method returns the release version, parsed from the tag
+    pub fn version(&self) -> Option<HyprlandVersion> {
+        self.tag.as_ref().and_then(|tag| tag.parse().ok())
+    }
+    /// This method returns the [Capabilities] of this version
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::for_version(self.version())
+    }
+}
+
+impl Capabilities {
+    /// This function detects the capabilities of the running Hyprland instance,
+    /// and sets them as the [current](Capabilities::current) ones
+    pub fn detect() -> HResult<Self> {
+        let caps = Version::get()?.capabilities();
+        Capabilities::set_current(caps);
+        Ok(caps)
+    }
+    /// This function detects the capabilities of the running Hyprland instance,
+    /// and sets them as the [current](Capabilities::current) ones (async)
+    pub async fn detect_async() -> HResult<Self> {
+        let caps = Version::get_async().await?.capabilities();
+        Capabilities::set_current(caps);
+        Ok(caps)
+    }
+}
+
 /// This struct holds information on the cursor position
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct CursorPosition {
     /// The x position of the cursor
     pub x: i64,
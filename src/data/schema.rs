@@ -0,0 +1,239 @@
+use super::*;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// This enum holds the modes used when deserializing data from Hyprland
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum DeserializeMode {
+    /// Unknown fields are ignored, and missing fields are an error
+    #[default]
+    Standard = 0,
+    /// Unknown fields are ignored, and missing fields are filled with their defaults
+    Lenient = 1,
+    /// Any unknown or missing field is an error (useful for CI)
+    Strict = 2,
+}
+
+static DESERIALIZE_MODE: AtomicU8 = AtomicU8::new(DeserializeMode::Standard as u8);
+
+/// This function sets the [DeserializeMode] used by every data query
+pub fn set_deserialize_mode(mode: DeserializeMode) {
+    DESERIALIZE_MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// This function returns the current [DeserializeMode]
+pub fn deserialize_mode() -> DeserializeMode {
+    match DESERIALIZE_MODE.load(Ordering::Relaxed) {
+        1 => DeserializeMode::Lenient,
+        2 => DeserializeMode::Strict,
+        _ => DeserializeMode::Standard,
+    }
+}
+
+/// Trait for getting data along with the [SchemaReport] of the query
+#[async_trait]
+pub trait HyprDataWithReport: HyprData {
+    /// This method gets the data, and the schema report
+    fn get_with_report() -> HResult<(Self, SchemaReport)>
+    where
+        Self: Sized;
+    /// This method gets the data, and the schema report (async)
+    async fn get_with_report_async() -> HResult<(Self, SchemaReport)>
+    where
+        Self: Sized;
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn diff_values(
+    path: &str,
+    raw: &Value,
+    typed: &Value,
+    unknown: &mut BTreeSet<String>,
+    missing: &mut BTreeSet<String>,
+) {
+    match (raw, typed) {
+        (Value::Object(raw), Value::Object(typed)) => {
+            for (key, raw_value) in raw {
+                match typed.get(key) {
+                    Some(typed_value) => diff_values(
+                        &join_path(path, key),
+                        raw_value,
+                        typed_value,
+                        unknown,
+                        missing,
+                    ),
+                    None => {
+                        unknown.insert(join_path(path, key));
+                    }
+                }
+            }
+            for (key, typed_value) in typed {
+                if !raw.contains_key(key) && !typed_value.is_null() {
+                    missing.insert(join_path(path, key));
+                }
+            }
+        }
+        (Value::Array(raw), Value::Array(typed)) => {
+            let path = format!("{path}[]");
+            for (raw_value, typed_value) in raw.iter().zip(typed) {
+                diff_values(&path, raw_value, typed_value, unknown, missing);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// This pub(crate) function compares the raw json with the deserialized value
+pub(crate) fn schema_report<T: Serialize>(
+    command: &str,
+    raw: String,
+    raw_value: &Value,
+    deserialized: &T,
+) -> HResult<SchemaReport> {
+    let typed = serde_json::to_value(deserialized)?;
+    let mut unknown = BTreeSet::new();
+    let mut missing = BTreeSet::new();
+    diff_values("", raw_value, &typed, &mut unknown, &mut missing);
+    Ok(SchemaReport {
+        command: command.to_string(),
+        unknown_fields: unknown.into_iter().collect(),
+        missing_fields: missing.into_iter().collect(),
+        raw,
+    })
+}
+
+/// This function returns the first field of the deserialized value missing from the
+/// raw json, without building a whole report
+fn first_missing(path: &str, raw: &Value, typed: &Value) -> Option<String> {
+    match (raw, typed) {
+        (Value::Object(raw), Value::Object(typed)) => {
+            typed
+                .iter()
+                .find_map(|(key, typed_value)| match raw.get(key) {
+                    Some(raw_value) => first_missing(&join_path(path, key), raw_value, typed_value),
+                    None if !typed_value.is_null() => Some(join_path(path, key)),
+                    None => None,
+                })
+        }
+        (Value::Array(raw), Value::Array(typed)) => {
+            let path = format!("{path}[]");
+            raw.iter()
+                .zip(typed)
+                .find_map(|(raw_value, typed_value)| first_missing(&path, raw_value, typed_value))
+        }
+        _ => None,
+    }
+}
+
+fn missing_field_error(command: &str, field: &str) -> HyprError {
+    HyprError::SerdeError(serde::de::Error::custom(format!(
+        "missing field `{field}` in `{command}`"
+    )))
+}
+
+/// This pub(crate) function deserializes data using the current [DeserializeMode]
+///
+/// The whole [SchemaReport] is only built in [DeserializeMode::Strict], use
+/// [parse_data_with_report] to always get it
+pub(crate) fn parse_data<T: DeserializeOwned + Serialize>(
+    command: &str,
+    raw: String,
+) -> HResult<T> {
+    match deserialize_mode() {
+        DeserializeMode::Lenient => Ok(serde_json::from_str(&raw)?),
+        DeserializeMode::Strict => Ok(parse_data_with_report(command, raw)?.0),
+        DeserializeMode::Standard => {
+            let raw_value: Value = serde_json::from_str(&raw)?;
+            let deserialized = T::deserialize(&raw_value)?;
+            let typed = serde_json::to_value(&deserialized)?;
+            match first_missing("", &raw_value, &typed) {
+                Some(field) => Err(missing_field_error(command, &field)),
+                None => Ok(deserialized),
+            }
+        }
+    }
+}
+
+/// This pub(crate) function deserializes data using the current [DeserializeMode],
+/// and returns its [SchemaReport]
+pub(crate) fn parse_data_with_report<T: DeserializeOwned + Serialize>(
+    command: &str,
+    raw: String,
+) -> HResult<(T, SchemaReport)> {
+    let raw_value: Value = serde_json::from_str(&raw)?;
+    let deserialized = T::deserialize(&raw_value)?;
+    let report = schema_report(command, raw, &raw_value, &deserialized)?;
+    match deserialize_mode() {
+        DeserializeMode::Lenient => Ok((deserialized, report)),
+        DeserializeMode::Strict if report.has_drift() => Err(HyprError::SchemaDrift(report)),
+        DeserializeMode::Standard if !report.missing_fields.is_empty() => {
+            Err(missing_field_error(command, &report.missing_fields[0]))
+        }
+        _ => Ok((deserialized, report)),
+    }
+}
+
+#[test]
+fn test_schema_report() {
+    let raw = r#"[{"id":1,"name":"1","extra":true,"nested":{"new":1}}]"#;
+    #[derive(serde::Deserialize, Serialize, Default)]
+    #[serde(default)]
+    struct Nested {
+        old: u8,
+    }
+    #[derive(serde::Deserialize, Serialize, Default)]
+    #[serde(default)]
+    struct Item {
+        id: u8,
+        name: String,
+        nested: Nested,
+        monitor: String,
+        optional: Option<String>,
+    }
+    let raw_value: Value = match serde_json::from_str(raw) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let items: Vec<Item> = match serde_json::from_value(raw_value.clone()) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let report = match schema_report("workspaces", raw.to_string(), &raw_value, &items) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(report.unknown_fields, vec!["[].extra", "[].nested.new"]);
+    assert_eq!(report.missing_fields, vec!["[].monitor", "[].nested.old"]);
+    assert!(report.has_drift());
+}
+
+#[test]
+fn test_first_missing() {
+    let parse = |raw: &str| match serde_json::from_str::<Value>(raw) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let typed = parse(r#"[{"id":1,"nested":{"old":0},"optional":null}]"#);
+    assert_eq!(
+        first_missing("", &parse(r#"[{"id":1,"nested":{}}]"#), &typed),
+        Some("[].nested.old".to_string())
+    );
+    assert_eq!(
+        first_missing(
+            "",
+            &parse(r#"[{"id":1,"nested":{"old":1},"new":2}]"#),
+            &typed
+        ),
+        None
+    );
+}
@@ -0,0 +1,348 @@
+//! Exec with inline rules, and waiting for the spawned window
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::{HResult, WorkspaceRef};
+//! # fn main() -> HResult<()> {
+//! use hyprland::dispatch::{Exec, ExecRule, SpawnMatch};
+//! use std::time::Duration;
+//! let client = Exec::new("kitty")
+//!     .rule(ExecRule::Workspace(WorkspaceRef::Id(3), true))
+//!     .rule(ExecRule::Float)
+//!     .rule(ExecRule::Size(800, 600))
+//!     .spawn_and_wait(SpawnMatch::Class("kitty".to_string()), Duration::from_secs(5))?;
+//! println!("kitty opened at {}", client.address);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+#[cfg(feature = "listener")]
+use crate::data::{Client, Clients};
+#[cfg(feature = "listener")]
+use crate::event_listener::{event_parser, Event, WindowOpenEvent};
+#[cfg(feature = "listener")]
+use std::io;
+#[cfg(feature = "listener")]
+use std::time::{Duration, Instant};
+
+/// This enum holds the window rules that can be applied to a program started with [Exec]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecRule {
+    /// Opens the window on a workspace, optionally without switching to it (silent)
+    Workspace(WorkspaceRef, bool),
+    /// Opens the window on a monitor
+    Monitor(MonitorRef),
+    /// Floats the window
+    Float,
+    /// Tiles the window
+    Tile,
+    /// Makes the window fullscreen
+    Fullscreen,
+    /// Maximizes the window
+    Maximize,
+    /// Pseudo tiles the window
+    Pseudo,
+    /// Pins the window (it has to be floating)
+    Pin,
+    /// Centers the window (it has to be floating)
+    Center,
+    /// Prevents the window from being focused
+    NoFocus,
+    /// Prevents the window from being focused when it opens
+    NoInitialFocus,
+    /// Sets the size of the window (it has to be floating)
+    Size(u16, u16),
+    /// Moves the window (it has to be floating)
+    Move(i32, i32),
+    /// Sets the opacity of the window
+    Opacity(f32),
+    /// Any other window rule, as written in the config (eg. `bordercolor rgb(ff0000)`)
+    Raw(String),
+}
+
+impl std::fmt::Display for ExecRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ExecRule::*;
+        match self {
+            Workspace(work, true) => write!(f, "workspace {work} silent"),
+            Workspace(work, false) => write!(f, "workspace {work}"),
+            Monitor(mon) => write!(f, "monitor {mon}"),
+            Float => write!(f, "float"),
+            Tile => write!(f, "tile"),
+            Fullscreen => write!(f, "fullscreen"),
+            Maximize => write!(f, "maximize"),
+            Pseudo => write!(f, "pseudo"),
+            Pin => write!(f, "pin"),
+            Center => write!(f, "center"),
+            NoFocus => write!(f, "nofocus"),
+            NoInitialFocus => write!(f, "noinitialfocus"),
+            Size(w, h) => write!(f, "size {w} {h}"),
+            Move(x, y) => write!(f, "move {x} {y}"),
+            Opacity(alpha) => write!(f, "opacity {alpha}"),
+            Raw(rule) => write!(f, "{rule}"),
+        }
+    }
+}
+
+/// This struct is a builder for the `exec` dispatcher, with inline window rules
+///
+/// It displays as the argument of the dispatcher (eg. `[workspace 3 silent; float] kitty`),
+/// so it can also be used with [DispatchType::Exec]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exec {
+    command: String,
+    rules: Vec<ExecRule>,
+}
+
+impl std::fmt::Display for Exec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.rules.is_empty() {
+            return write!(f, "{}", self.command);
+        }
+        let rules: Vec<String> = self.rules.iter().map(ToString::to_string).collect();
+        write!(f, "[{}] {}", rules.join("; "), self.command)
+    }
+}
+
+impl Exec {
+    /// Creates a builder for a command
+    pub fn new<T: ToString>(command: T) -> Self {
+        Self {
+            command: command.to_string(),
+            rules: vec![],
+        }
+    }
+
+    /// Adds a window rule, which is applied to the windows of the spawned program
+    pub fn rule(mut self, rule: ExecRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// This method spawns the program (blocking)
+    pub fn call(&self) -> HResult<()> {
+        Dispatch::call(DispatchType::Exec(&self.to_string()))
+    }
+
+    /// This method spawns the program (async)
+    pub async fn call_async(&self) -> HResult<()> {
+        Dispatch::call_async(DispatchType::Exec(&self.to_string())).await
+    }
+}
+
+/// This enum is used to find the window of a program spawned with [Exec::spawn_and_wait]
+#[cfg(feature = "listener")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpawnMatch {
+    /// The first window opened with this class
+    Class(String),
+    /// The first window opened
+    Any,
+}
+
+#[cfg(feature = "listener")]
+impl SpawnMatch {
+    fn matches_event(&self, window: &WindowOpenEvent) -> bool {
+        match self {
+            SpawnMatch::Class(class) => &window.2 == class,
+            SpawnMatch::Any => true,
+        }
+    }
+}
+
+/// Reads the rest of a event line, a partial line is kept in `line` until the rest arrives,
+/// even if it ends in the middle of a character
+#[cfg(feature = "listener")]
+fn read_event_line(
+    reader: &mut impl io::BufRead,
+    line: &mut Vec<u8>,
+) -> io::Result<Option<String>> {
+    if reader.read_until(b'\n', line)? == 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    if line.last() != Some(&b'\n') {
+        return Ok(None);
+    }
+    let string = String::from_utf8_lossy(line).into_owned();
+    line.clear();
+    Ok(Some(string))
+}
+
+#[cfg(feature = "listener")]
+impl Exec {
+    fn timed_out(&self, matcher: &SpawnMatch, timeout: Duration) -> HyprError {
+        HyprError::IoError(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("no window matching {matcher:?} was opened by {self:?} within {timeout:?}"),
+        ))
+    }
+
+    /// This method spawns the program, and waits until its window opens (blocking)
+    ///
+    /// If no matching window opens before the timeout, an [io::ErrorKind::TimedOut] error
+    /// is returned
+    pub fn spawn_and_wait(&self, matcher: SpawnMatch, timeout: Duration) -> HResult<Client> {
+        use std::os::unix::net::UnixStream;
+
+        let deadline = Instant::now() + timeout;
+        // the listener is connected before spawning, so the event can't be missed
+        let mut reader =
+            io::BufReader::new(UnixStream::connect(get_socket_path(SocketType::Listener))?);
+        self.call()?;
+
+        let mut line = vec![];
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(self.timed_out(&matcher, timeout));
+            }
+            reader.get_ref().set_read_timeout(Some(remaining))?;
+
+            let string = match read_event_line(&mut reader, &mut line) {
+                Ok(Some(string)) => string,
+                Ok(None) => continue,
+                Err(error)
+                    if matches!(
+                        error.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    return Err(self.timed_out(&matcher, timeout))
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            for event in event_parser(string)? {
+                if let Event::WindowOpened(window) = event {
+                    if !matcher.matches_event(&window) {
+                        continue;
+                    }
+                    if let Some(client) = Clients::get()?.find(|client| client.address == window.0)
+                    {
+                        return Ok(client);
+                    }
+                }
+            }
+        }
+    }
+
+    /// This method spawns the program, and waits until its window opens (async)
+    ///
+    /// If no matching window opens before the timeout, an [io::ErrorKind::TimedOut] error
+    /// is returned
+    pub async fn spawn_and_wait_async(
+        &self,
+        matcher: SpawnMatch,
+        timeout: Duration,
+    ) -> HResult<Client> {
+        use crate::unix_async::*;
+
+        // the listener is connected before spawning, so the event can't be missed
+        let stream = UnixStream::connect(get_socket_path(SocketType::Listener)).await?;
+        let mut reader = BufReader::new(stream);
+        self.call_async().await?;
+
+        let wait = async {
+            // events are read a line at a time, a partial line is kept until the rest arrives
+            let mut line = vec![];
+            loop {
+                line.clear();
+                if reader.read_until(b'\n', &mut line).await? == 0 {
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+                }
+                let string = String::from_utf8_lossy(&line).into_owned();
+
+                for event in event_parser(string)? {
+                    if let Event::WindowOpened(window) = event {
+                        if !matcher.matches_event(&window) {
+                            continue;
+                        }
+                        if let Some(client) = Clients::get_async()
+                            .await?
+                            .find(|client| client.address == window.0)
+                        {
+                            return Ok(client);
+                        }
+                    }
+                }
+            }
+        };
+
+        match with_timeout(timeout, wait).await {
+            Some(result) => result,
+            None => Err(self.timed_out(&matcher, timeout)),
+        }
+    }
+}
+
+#[test]
+fn test_exec_rules() {
+    assert_eq!(Exec::new("kitty").to_string(), "kitty");
+    assert_eq!(
+        Exec::new("kitty --hold")
+            .rule(ExecRule::Workspace(WorkspaceRef::Id(3), true))
+            .rule(ExecRule::Float)
+            .rule(ExecRule::Size(800, 600))
+            .to_string(),
+        "[workspace 3 silent; float; size 800 600] kitty --hold"
+    );
+    assert_eq!(
+        Exec::new("firefox")
+            .rule(ExecRule::Workspace(
+                WorkspaceRef::Special(Some("web".to_string())),
+                false
+            ))
+            .rule(ExecRule::Monitor(MonitorRef::Name("DP-1".to_string())))
+            .rule(ExecRule::Raw("bordercolor rgb(ff0000)".to_string()))
+            .to_string(),
+        "[workspace special:web; monitor DP-1; bordercolor rgb(ff0000)] firefox"
+    );
+}
+
+#[cfg(feature = "listener")]
+#[test]
+fn test_event_line_split_in_a_character() {
+    use std::collections::VecDeque;
+    use std::io::Read;
+
+    // replies with each chunk, and a timeout between them
+    struct Chunks(VecDeque<io::Result<Vec<u8>>>);
+    impl Read for Chunks {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.pop_front() {
+                Some(Ok(chunk)) => {
+                    buf[..chunk.len()].copy_from_slice(&chunk);
+                    Ok(chunk.len())
+                }
+                Some(Err(error)) => Err(error),
+                None => Ok(0),
+            }
+        }
+    }
+    let event = "openwindow>>55d6ab6a4b50,1,kitty,café\n".as_bytes();
+    let split = event.len() - 2;
+    let mut reader = io::BufReader::new(Chunks(VecDeque::from([
+        Ok(event[..split].to_vec()),
+        Err(io::ErrorKind::WouldBlock.into()),
+        Ok(event[split..].to_vec()),
+    ])));
+    let mut line = vec![];
+    assert!(read_event_line(&mut reader, &mut line).is_err());
+    let string = match read_event_line(&mut reader, &mut line) {
+        Ok(Some(string)) => string,
+        other => panic!("Expected a line, got {other:?}"),
+    };
+    let events = match event_parser(string) {
+        Ok(events) => events,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert!(matches!(
+        &events[..],
+        [Event::WindowOpened(WindowOpenEvent(_, _, _, title))] if title == "café"
+    ));
+    assert!(matches!(
+        read_event_line(&mut reader, &mut line),
+        Err(error) if error.kind() == io::ErrorKind::UnexpectedEof
+    ));
+}
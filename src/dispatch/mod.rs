@@ -0,0 +1,1457 @@
+//! # Dispatch module
+//!
+//! This module is used for calling dispatchers and changing keywords
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use hyprland::shared::HResult;
+//! use hyprland::dispatch::{Dispatch, DispatchType};
+//! fn main() -> HResult<()> {
+//!    Dispatch::call(DispatchType::Exec("kitty"))?;
+//!
+//!    Ok(())
+//! }
+//! ````
+
+use crate::shared::*;
+use serde::{Deserialize, Serialize};
+use std::string::ToString;
+use strum_macros::{Display, EnumString};
+
+mod exec;
+mod owned;
+mod parse;
+pub use crate::dispatch::exec::*;
+pub use crate::dispatch::owned::*;
+
+/// This enum is for identifying a window
+///
+/// It covers the window selector grammar Hyprland accepts, and can be parsed
+/// from it (eg. `"class:^(kitty)$".parse::<WindowIdentifier>()`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum WindowIdentifier {
+    /// The address of a window
+    Address(Address),
+    /// A Regular Expression to match the window class (handled by Hyprland)
+    ClassRegularExpression(String),
+    /// A Regular Expression to match the initial window class
+    InitialClass(String),
+    /// A Regular Expression to match the window title
+    Title(String),
+    /// A Regular Expression to match the initial window title
+    InitialTitle(String),
+    /// A window tag
+    Tag(String),
+    /// The window's process Id
+    ProcessId(u32),
+    /// The active window
+    ActiveWindow,
+    /// The first floating window on the current workspace
+    Floating,
+    /// The first tiled window on the current workspace
+    Tiled,
+}
+
+impl std::fmt::Display for WindowIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            WindowIdentifier::Address(addr) => format!("address:{addr}"),
+            WindowIdentifier::ProcessId(id) => format!("pid:{id}"),
+            WindowIdentifier::ClassRegularExpression(regex) => format!("class:{regex}"),
+            WindowIdentifier::InitialClass(regex) => format!("initialclass:{regex}"),
+            WindowIdentifier::Title(title) => format!("title:{title}"),
+            WindowIdentifier::InitialTitle(title) => format!("initialtitle:{title}"),
+            WindowIdentifier::Tag(tag) => format!("tag:{tag}"),
+            WindowIdentifier::ActiveWindow => "activewindow".to_string(),
+            WindowIdentifier::Floating => "floating".to_string(),
+            WindowIdentifier::Tiled => "tiled".to_string(),
+        };
+        write!(f, "{out}")
+    }
+}
+
+impl std::str::FromStr for WindowIdentifier {
+    type Err = HyprError;
+
+    /// Parses a window selector, a selector without a known prefix is a class regex (like in Hyprland)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(HyprError::ParseError(
+                "A window selector can't be empty".to_string(),
+            ));
+        }
+        Ok(match s {
+            "activewindow" => WindowIdentifier::ActiveWindow,
+            "floating" => WindowIdentifier::Floating,
+            "tiled" => WindowIdentifier::Tiled,
+            _ => match s.split_once(':') {
+                Some(("address", addr)) => WindowIdentifier::Address(addr.parse()?),
+                Some(("pid", pid)) => WindowIdentifier::ProcessId(pid.parse().map_err(|_| {
+                    HyprError::ParseError(format!("{pid:?} is not a valid process id"))
+                })?),
+                Some(("class", regex)) => {
+                    WindowIdentifier::ClassRegularExpression(regex.to_string())
+                }
+                Some(("initialclass", regex)) => WindowIdentifier::InitialClass(regex.to_string()),
+                Some(("title", regex)) => WindowIdentifier::Title(regex.to_string()),
+                Some(("initialtitle", regex)) => WindowIdentifier::InitialTitle(regex.to_string()),
+                Some(("tag", tag)) => WindowIdentifier::Tag(tag.to_string()),
+                _ => WindowIdentifier::ClassRegularExpression(s.to_string()),
+            },
+        })
+    }
+}
+
+impl TryFrom<String> for WindowIdentifier {
+    type Error = HyprError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<WindowIdentifier> for String {
+    fn from(win: WindowIdentifier) -> Self {
+        win.to_string()
+    }
+}
+
+impl From<Address> for WindowIdentifier {
+    fn from(addr: Address) -> Self {
+        WindowIdentifier::Address(addr)
+    }
+}
+
+/// This enum holds the fullscreen types
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FullscreenType {
+    /// Fills the whole screen
+    #[strum(serialize = "0")]
+    Real,
+    /// Maximizes the window
+    #[strum(serialize = "1")]
+    Maximize,
+    /// Passes no param
+    #[strum(serialize = "")]
+    NoParam,
+}
+
+/// This enum holds directions, typically used for moving
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum Direction {
+    #[strum(serialize = "u")]
+    Up,
+    #[strum(serialize = "d")]
+    Down,
+    #[strum(serialize = "r")]
+    Right,
+    #[strum(serialize = "l")]
+    Left,
+}
+
+/// This enum is used for resizing and moving windows precisely
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Position {
+    /// A delta
+    Delta(i16, i16),
+    /// The exact size
+    Exact(i16, i16),
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            Position::Delta(x, y) => format!("{x} {y}"),
+            Position::Exact(w, h) => format!("exact {w} {h}"),
+        };
+        write!(f, "{out}")
+    }
+}
+
+/// This enum holds a direction for cycling
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CycleDirection {
+    #[strum(serialize = "")]
+    Next,
+    #[strum(serialize = "prev")]
+    Previous,
+}
+
+/// This enum is used for identifying monitors
+#[derive(Debug, Clone, PartialEq)]
+pub enum MonitorIdentifier<'a> {
+    /// The monitor that is to the specified direction of the active one
+    Direction(Direction),
+    /// The monitor id
+    Id(MonitorId),
+    /// The monitor name
+    Name(&'a str),
+    /// The current monitor
+    Current,
+    /// The workspace relative to the current workspace
+    Relative(i32),
+}
+
+impl std::fmt::Display for MonitorIdentifier<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            MonitorIdentifier::Direction(dir) => dir.to_string(),
+            MonitorIdentifier::Id(id) => id.to_string(),
+            MonitorIdentifier::Name(name) => name.to_string(),
+            MonitorIdentifier::Current => "current".to_string(),
+            MonitorIdentifier::Relative(int) => format_relative(*int, ""),
+        };
+        write!(f, "{out}")
+    }
+}
+
+impl<'a> From<&'a MonitorRef> for MonitorIdentifier<'a> {
+    fn from(monitor: &'a MonitorRef) -> Self {
+        match monitor {
+            MonitorRef::Id(id) => MonitorIdentifier::Id(*id),
+            MonitorRef::Name(name) => MonitorIdentifier::Name(name),
+        }
+    }
+}
+
+/// This enum holds corners
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Corner {
+    TopRight = 0,
+    TopLeft = 1,
+    BottomRight = 2,
+    BottomLeft = 3,
+}
+
+/// This enum holds options that are applied to the current workspace
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceOptions {
+    /// Makes all windows pseudo tiled
+    #[strum(serialize = "allpseudo")]
+    AllPseudo,
+    /// Makes all windows float
+    #[strum(serialize = "allfloat")]
+    AllFloat,
+}
+
+/// This enum is for identifying workspaces
+///
+/// It covers the workspace selector grammar Hyprland accepts, and can be parsed from it
+/// with [WorkspaceIdentifier::parse] (or [str::parse] for [OwnedWorkspaceIdentifier])
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkspaceIdentifier<'a> {
+    /// The workspace Id, it can't be negative (those are special workspaces, which are
+    /// selected by name), as it would be read as a relative workspace
+    Id(WorkspaceId),
+    /// The workspace relative to the current workspace (`+1`)
+    Relative(i32),
+    /// The workspace on the monitor relative to the current one (`m+1`)
+    RelativeMonitor(i32),
+    /// The workspace on the monitor at a position (`m~1`)
+    AbsoluteMonitor(i32),
+    /// The workspace on the monitor relative to the current one, including empty workspaces (`r+1`)
+    RelativeMonitorIncludingEmpty(i32),
+    /// The workspace on the monitor at a position, including empty workspaces (`r~1`)
+    AbsoluteMonitorIncludingEmpty(i32),
+    /// The open workspace relative to the current one (`e+1`)
+    RelativeOpen(i32),
+    /// The open workspace at a position (`e~1`)
+    AbsoluteOpen(i32),
+    /// The previous Workspace
+    Previous,
+    /// The previous Workspace on the current monitor
+    PreviousPerMonitor,
+    /// The first available empty workspace
+    Empty,
+    /// The first available empty workspace on the current monitor
+    EmptyOnMonitor,
+    /// The next available empty workspace after the current one
+    EmptyNext,
+    /// The name of the workspace
+    Name(&'a str),
+    /// The special workspace
+    Special(Option<&'a str>),
+}
+
+/// This type is the same as [WorkspaceIdentifier], which now includes the special workspace
+pub type WorkspaceIdentifierWithSpecial<'a> = WorkspaceIdentifier<'a>;
+
+impl std::fmt::Display for WorkspaceIdentifier<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use WorkspaceIdentifier::*;
+        let out = match self {
+            Id(id) => format!("{id}"),
+            Name(name) => format!("name:{name}"),
+            Relative(int) => format_relative(*int, ""),
+            RelativeMonitor(int) => format_relative(*int, "m"),
+            AbsoluteMonitor(int) => format!("m~{int}"),
+            RelativeMonitorIncludingEmpty(int) => format_relative(*int, "r"),
+            AbsoluteMonitorIncludingEmpty(int) => format!("r~{int}"),
+            RelativeOpen(int) => format_relative(*int, "e"),
+            AbsoluteOpen(int) => format!("e~{int}"),
+            Previous => "previous".to_string(),
+            PreviousPerMonitor => "previous_per_monitor".to_string(),
+            Empty => "empty".to_string(),
+            EmptyOnMonitor => "emptym".to_string(),
+            EmptyNext => "emptyn".to_string(),
+            Special(opt) => match opt {
+                Some(name) => format!("special:{name}"),
+                None => "special".to_string(),
+            },
+        };
+
+        write!(f, "{out}")
+    }
+}
+
+impl<'a> From<&'a WorkspaceRef> for WorkspaceIdentifier<'a> {
+    fn from(work: &'a WorkspaceRef) -> Self {
+        match work {
+            WorkspaceRef::Id(id) => WorkspaceIdentifier::Id(*id),
+            WorkspaceRef::Name(name) => WorkspaceIdentifier::Name(name),
+            WorkspaceRef::Special(name) => WorkspaceIdentifier::Special(name.as_deref()),
+        }
+    }
+}
+
+/// This enum holds the window to make active in a group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupActive {
+    /// The next window in the group
+    Forward,
+    /// The previous window in the group
+    Back,
+    /// The window at a index in the group (starting at 1)
+    Index(u8),
+}
+
+impl std::fmt::Display for GroupActive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupActive::Forward => write!(f, "f"),
+            GroupActive::Back => write!(f, "b"),
+            GroupActive::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+/// This enum holds the directions a window can be moved within its group
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum GroupDirection {
+    #[strum(serialize = "f")]
+    Forward,
+    #[strum(serialize = "b")]
+    Back,
+}
+
+/// This enum holds the actions for locking groups
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum LockType {
+    #[strum(serialize = "lock")]
+    Lock,
+    #[strum(serialize = "unlock")]
+    Unlock,
+    #[strum(serialize = "toggle")]
+    Toggle,
+}
+
+/// This enum holds the states a toggleable property can be set to
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum ToggleState {
+    #[strum(serialize = "on")]
+    On,
+    #[strum(serialize = "off")]
+    Off,
+    #[strum(serialize = "toggle")]
+    Toggle,
+}
+
+/// This enum is used for changing split ratios
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitRatio {
+    /// A change relative to the current ratio
+    Relative(f32),
+    /// The exact ratio
+    Exact(f32),
+}
+
+/// A bare ratio is relative, which is what [DispatchType::ChangeSplitRatio] took before
+/// [SplitRatio] was added
+impl From<f32> for SplitRatio {
+    fn from(ratio: f32) -> Self {
+        SplitRatio::Relative(ratio)
+    }
+}
+
+impl std::fmt::Display for SplitRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplitRatio::Relative(ratio) => write!(f, "{ratio}"),
+            SplitRatio::Exact(ratio) => write!(f, "exact {ratio}"),
+        }
+    }
+}
+
+/// This enum holds the orientations of the master layout
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum MasterOrientation {
+    #[strum(serialize = "left")]
+    Left,
+    #[strum(serialize = "right")]
+    Right,
+    #[strum(serialize = "top")]
+    Top,
+    #[strum(serialize = "bottom")]
+    Bottom,
+    #[strum(serialize = "center")]
+    Center,
+}
+
+/// This enum holds the messages that can be sent to the current layout
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutMessage {
+    /// (dwindle) Toggles the split direction of the active window
+    ToggleSplit,
+    /// (dwindle) Swaps the two halves of the split of the active window
+    SwapSplit,
+    /// (dwindle) Makes the next window open in a specified direction of the active one
+    PreSelect(Direction),
+    /// (master) Swaps the active window with the master
+    SwapWithMaster,
+    /// (master) Focuses the master window
+    FocusMaster,
+    /// (master) Focuses the next window
+    CycleNext,
+    /// (master) Focuses the previous window
+    CyclePrev,
+    /// (master) Adds a master to the master side
+    AddMaster,
+    /// (master) Removes a master from the master side
+    RemoveMaster,
+    /// (master) Sets the orientation of the current workspace
+    Orientation(MasterOrientation),
+    /// (master) Cycles to the next orientation of the current workspace
+    OrientationNext,
+    /// (master) Cycles to the previous orientation of the current workspace
+    OrientationPrev,
+    /// (master) Changes the master split ratio
+    MFact(SplitRatio),
+}
+
+impl std::fmt::Display for LayoutMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use LayoutMessage::*;
+        match self {
+            ToggleSplit => write!(f, "togglesplit"),
+            SwapSplit => write!(f, "swapsplit"),
+            PreSelect(dir) => write!(f, "preselect {dir}"),
+            SwapWithMaster => write!(f, "swapwithmaster"),
+            FocusMaster => write!(f, "focusmaster"),
+            CycleNext => write!(f, "cyclenext"),
+            CyclePrev => write!(f, "cycleprev"),
+            AddMaster => write!(f, "addmaster"),
+            RemoveMaster => write!(f, "removemaster"),
+            Orientation(orientation) => write!(f, "orientation{orientation}"),
+            OrientationNext => write!(f, "orientationnext"),
+            OrientationPrev => write!(f, "orientationprev"),
+            MFact(ratio) => write!(f, "mfact {ratio}"),
+        }
+    }
+}
+
+/// This enum is used for identifying submaps
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmapName<'a> {
+    /// A submap by its name
+    Name(&'a str),
+    /// The default submap (resets the current one)
+    Reset,
+}
+
+impl std::fmt::Display for SubmapName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmapName::Name(name) => write!(f, "{name}"),
+            SubmapName::Reset => write!(f, "reset"),
+        }
+    }
+}
+
+/// This enum holds the window properties that can be set with [DispatchType::SetProp]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowProperty {
+    /// The opacity of the window when active
+    Alpha(f32),
+    /// The opacity of the window when inactive
+    AlphaInactive(f32),
+    /// The rounding of the window corners
+    Rounding(u16),
+    /// The size of the window border
+    BorderSize(u16),
+    /// The border color, in Hyprland's color format (eg. `rgba(33ccffee) rgba(00ff99ee) 45deg`)
+    BorderColor(String),
+    /// The max size of the window
+    MaxSize(u16, u16),
+    /// The min size of the window
+    MinSize(u16, u16),
+    /// Disables the max size of the window
+    NoMaxSize(bool),
+    /// Forces the window to not be blurred
+    ForceNoBlur(bool),
+    /// Forces the window to be opaque
+    ForceOpaque(bool),
+    /// Forces the window to not be animated
+    ForceNoAnims(bool),
+    /// Forces the window to have no border
+    ForceNoBorder(bool),
+    /// Forces the window to have no shadow
+    ForceNoShadow(bool),
+    /// Forces the window to not be dimmed
+    ForceNoDim(bool),
+    /// Prevents the window from being focused
+    NoFocus(bool),
+    /// Keeps the aspect ratio of the window when resizing
+    KeepAspectRatio(bool),
+    /// Enables the compatibility workaround for windowdance
+    WindowDanceCompat(bool),
+}
+
+impl std::fmt::Display for WindowProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use WindowProperty::*;
+        let flag = |name: &str, value: &bool| format!("{name} {}", *value as u8);
+        let out = match self {
+            Alpha(alpha) => format!("alpha {alpha}"),
+            AlphaInactive(alpha) => format!("alphainactive {alpha}"),
+            Rounding(rounding) => format!("rounding {rounding}"),
+            BorderSize(size) => format!("bordersize {size}"),
+            BorderColor(color) => format!("bordercolor {color}"),
+            MaxSize(w, h) => format!("maxsize {w} {h}"),
+            MinSize(w, h) => format!("minsize {w} {h}"),
+            NoMaxSize(value) => flag("nomaxsize", value),
+            ForceNoBlur(value) => flag("forcenoblur", value),
+            ForceOpaque(value) => flag("forceopaque", value),
+            ForceNoAnims(value) => flag("forcenoanims", value),
+            ForceNoBorder(value) => flag("forcenoborder", value),
+            ForceNoShadow(value) => flag("forcenoshadow", value),
+            ForceNoDim(value) => flag("forcenodim", value),
+            NoFocus(value) => flag("nofocus", value),
+            KeepAspectRatio(value) => flag("keepaspectratio", value),
+            WindowDanceCompat(value) => flag("windowdance", value),
+        };
+        write!(f, "{out}")
+    }
+}
+
+/// This enum holds the positions a window can be moved to in the stacking order
+#[derive(Debug, Clone, PartialEq, Display, EnumString, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum ZOrder {
+    #[strum(serialize = "top")]
+    Top,
+    #[strum(serialize = "bottom")]
+    Bottom,
+}
+
+/// This enum holds the fullscreen modes used by [DispatchType::FullscreenState]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FullscreenMode {
+    /// Keeps the current mode
+    Current = -1,
+    /// Not fullscreen
+    None = 0,
+    /// Maximized
+    Maximized = 1,
+    /// Fullscreen
+    Fullscreen = 2,
+    /// Maximized and fullscreen
+    MaximizedFullscreen = 3,
+}
+
+impl std::fmt::Display for FullscreenMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as i8)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+/// Enum for mod keys used in bind combinations and shortcuts
+pub enum Mod {
+    SUPER,
+    SHIFT,
+    ALT,
+    CTRL,
+    NONE,
+}
+
+impl std::fmt::Display for Mod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Mod::NONE => "",
+                Mod::SUPER => "SUPER",
+                Mod::SHIFT => "SHIFT",
+                Mod::ALT => "ALT",
+                Mod::CTRL => "CTRL",
+            }
+        )
+    }
+}
+
+/// This enum holds the key of a shortcut sent with [DispatchType::SendShortcut]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutKey {
+    /// A key by its xkb name (eg. `T`, `Return`, `F5`)
+    Key(String),
+    /// A key by its keycode
+    Code(u32),
+    /// A mouse button by its code (eg. `272` for the left button)
+    Mouse(u32),
+}
+
+impl std::fmt::Display for ShortcutKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShortcutKey::Key(key) => write!(f, "{key}"),
+            ShortcutKey::Code(code) => write!(f, "code:{code}"),
+            ShortcutKey::Mouse(button) => write!(f, "mouse:{button}"),
+        }
+    }
+}
+
+impl From<&str> for ShortcutKey {
+    fn from(key: &str) -> Self {
+        ShortcutKey::Key(key.to_string())
+    }
+}
+
+/// This enum is the params to MoveWindow dispatcher
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowMove<'a> {
+    /// Moves the window to a specified monitor
+    Monitor(MonitorIdentifier<'a>),
+    /// Moves the window in a specified direction
+    Direction(Direction),
+}
+
+/// This enum holds every dispatcher
+#[derive(Debug, Clone, PartialEq)]
+pub enum DispatchType<'a> {
+    /// This dispatcher changes the current cursor
+    SetCursor(
+        /// The cursor theme
+        &'a str,
+        /// The size
+        u16,
+    ),
+    /// This dispatcher executes a program
+    Exec(&'a str),
+    /// This dispatcher passes a keybind to a window when called in a
+    /// keybind, its used for global keybinds. And should **ONLY** be used with keybinds
+    Pass(WindowIdentifier),
+    /// This dispatcher kills the active window/client
+    KillActiveWindow,
+    /// This dispatcher closes the specified window
+    CloseWindow(WindowIdentifier),
+    /// This dispatcher changes the current workspace
+    Workspace(WorkspaceIdentifier<'a>),
+    /// This dispatcher changes the workspace of the focused monitor, moving the workspace to it
+    /// if it is on another monitor
+    FocusWorkspaceOnCurrentMonitor(WorkspaceIdentifier<'a>),
+    /// This dispatcher moves a window (focused if not specified) to a workspace
+    MoveToWorkspace(WorkspaceIdentifier<'a>, Option<WindowIdentifier>),
+    /// This dispatcher moves a window (focused if not specified) to a workspace, without switching to that
+    /// workspace
+    MoveToWorkspaceSilent(WorkspaceIdentifier<'a>, Option<WindowIdentifier>),
+    /// This dispatcher moves the focused window to a specified workspace, and
+    /// changes the active workspace aswell
+    MoveFocusedWindowToWorkspace(WorkspaceIdentifier<'a>),
+    /// This dispatcher moves the focused window to a specified workspace, and
+    /// does not change workspaces
+    MoveFocusedWindowToWorkspaceSilent(WorkspaceIdentifier<'a>),
+    /// This dispatcher floats a window (current if not specified)
+    ToggleFloating(Option<WindowIdentifier>),
+    /// This dispatcher toggles the current window fullscreen state
+    ToggleFullscreen(FullscreenType),
+    /// This dispatcher toggles the focused window’s internal
+    /// fullscreen state without altering the geometry
+    ToggleFakeFullscreen,
+    /// This dispatcher sets the DPMS status for all monitors
+    ToggleDPMS(bool, Option<&'a str>),
+    /// This dispatcher toggles pseudo tiling for the current window
+    TogglePseudo,
+    /// This dispatcher pins the active window to all workspaces
+    TogglePin,
+    /// This dispatcher moves the window focus in a specified direction
+    MoveFocus(Direction),
+    /// This dispatcher moves the current window to a monitor or in a specified direction
+    MoveWindow(WindowMove<'a>),
+    /// This dispatcher centers the active window
+    CenterWindow,
+    /// This dispatcher resizes the active window using a [`Position`][Position] enum
+    ResizeActive(Position),
+    /// This dispatcher moves the active window using a [`Position`][Position] enum
+    MoveActive(Position),
+    /// This dispatcher resizes the specified window using a [`Position`][Position] enum
+    ResizeWindowPixel(Position, WindowIdentifier),
+    /// This dispatcher moves the specified window using a [`Position`][Position] enum
+    MoveWindowPixel(Position, WindowIdentifier),
+    /// This dispatcher cycles windows using a specified direction
+    CycleWindow(CycleDirection),
+    /// This dispatcher swaps windows using a specified direction
+    SwapWindow(CycleDirection),
+    /// This dispatcher focuses a specified window
+    FocusWindow(WindowIdentifier),
+    /// This dispatcher focuses a specified monitor
+    FocusMonitor(MonitorIdentifier<'a>),
+    /// This dispatcher changed the split ratio
+    ChangeSplitRatio(SplitRatio),
+    /// This dispatcher sends a message to the current layout
+    LayoutMessage(LayoutMessage),
+    /// This dispatcher toggle opacity for the current window/client
+    ToggleOpaque,
+    /// This dispatcher moves the cursor to a specified corner of a window
+    MoveCursorToCorner(Corner),
+    /// This dispatcher applied a option to all windows in a workspace
+    WorkspaceOption(WorkspaceOptions),
+    /// This dispatcher renames a workspace
+    RenameWorkspace(WorkspaceId, Option<&'a str>),
+    /// This exits Hyprland **(DANGEROUS)**
+    Exit,
+    /// This dispatcher forces the renderer to reload
+    ForceRendererReload,
+    /// This dispatcher moves the current workspace to a specified monitor
+    MoveCurrentWorkspaceToMonitor(MonitorIdentifier<'a>),
+    /// This dispatcher moves a specified workspace to a specified monitor
+    MoveWorkspaceToMonitor(WorkspaceIdentifier<'a>, MonitorIdentifier<'a>),
+    /// This dispatcher swaps the active workspaces of two monitors
+    SwapActiveWorkspaces(MonitorIdentifier<'a>, MonitorIdentifier<'a>),
+    /// This dispatcher brings the active window to the top of the stack
+    BringActiveToTop,
+    /// This toggles the special workspace (AKA scratchpad)
+    ToggleSpecialWorkspace,
+    /// This dispatcher jump to urgent or the last window
+    FocusUrgentOrLast,
+    /// This dispatcher toggles the current window into (or out of) a group
+    ToggleGroup,
+    /// This dispatcher changes the active window in the current group
+    ChangeGroupActive(GroupActive),
+    /// This dispatcher moves the active window into the group in a specified direction
+    MoveIntoGroup(Direction),
+    /// This dispatcher moves a window (active if not specified) out of its group
+    MoveOutOfGroup(Option<WindowIdentifier>),
+    /// This dispatcher moves the active window in a specified direction, going into and
+    /// out of groups on its way
+    MoveWindowOrGroup(Direction),
+    /// This dispatcher swaps the active window with the next or previous one in its group
+    MoveGroupWindow(GroupDirection),
+    /// This dispatcher locks (or unlocks) all groups
+    LockGroups(LockType),
+    /// This dispatcher locks (or unlocks) the current group
+    LockActiveGroup(LockType),
+    /// This dispatcher prohibits the active window from becoming or being added to a group
+    DenyWindowFromGroup(ToggleState),
+    /// This dispatcher temporarily ignores the group lock, to add windows to locked groups
+    SetIgnoreGroupLock(ToggleState),
+    /// This dispatcher changes the current submap (a set of modal keybinds)
+    Submap(SubmapName<'a>),
+    /// This dispatcher sets a property of a window, optionally locking it so window rules
+    /// can't change it
+    SetProp(WindowIdentifier, WindowProperty, bool),
+    /// This dispatcher tiles a window (current if not specified)
+    SetTiled(Option<WindowIdentifier>),
+    /// This dispatcher floats a window (current if not specified)
+    SetFloating(Option<WindowIdentifier>),
+    /// This dispatcher pins a specified window to all workspaces
+    PinWindow(WindowIdentifier),
+    /// This dispatcher moves a window (current if not specified) to the top or bottom
+    /// of the stack
+    AlterZOrder(ZOrder, Option<WindowIdentifier>),
+    /// This dispatcher tags a window (current if not specified), a tag prefixed with `+`
+    /// is set, with `-` is unset and otherwise toggled
+    TagWindow(&'a str, Option<WindowIdentifier>),
+    /// This dispatcher sets the internal and the client fullscreen modes of the active window
+    FullscreenState(
+        /// The internal mode
+        FullscreenMode,
+        /// The mode sent to the client
+        FullscreenMode,
+    ),
+    /// This dispatcher switches focus to the previously focused window, from the current one
+    FocusCurrentOrLast,
+    /// This dispatcher moves the cursor to a position in the layout
+    MoveCursor(i32, i32),
+    /// This dispatcher sends a keyboard shortcut to a window (active if not specified),
+    /// without focusing it
+    SendShortcut(Vec<Mod>, ShortcutKey, Option<WindowIdentifier>),
+    /// This dispatcher triggers a global shortcut registered by an app
+    Global(
+        /// The app id
+        &'a str,
+        /// The shortcut name
+        &'a str,
+    ),
+}
+
+fn format_relative<T: Ord + std::fmt::Display + num_traits::Signed>(
+    int: T,
+    extra: &'_ str,
+) -> String {
+    if int.is_positive() {
+        format!("{extra}+{int}")
+    } else if int.is_negative() {
+        format!("{extra}{int}")
+    } else {
+        format!("{extra}+0")
+    }
+}
+
+impl DispatchType<'_> {
+    /// The workspace selector of the dispatcher, if it has one
+    pub(crate) fn workspace(&self) -> Option<&WorkspaceIdentifier<'_>> {
+        use DispatchType::*;
+        match self {
+            Workspace(work)
+            | FocusWorkspaceOnCurrentMonitor(work)
+            | MoveToWorkspace(work, _)
+            | MoveToWorkspaceSilent(work, _)
+            | MoveFocusedWindowToWorkspace(work)
+            | MoveFocusedWindowToWorkspaceSilent(work)
+            | MoveWorkspaceToMonitor(work, _) => Some(work),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn gen_dispatch_str(cmd: DispatchType, dispatch: bool) -> HResult<String> {
+    use DispatchType::*;
+    let caps = Capabilities::current();
+    // Hyprland reads `-5` as a relative workspace, so a negative id can't be selected
+    if let Some(WorkspaceIdentifier::Id(id)) = cmd.workspace() {
+        if *id < 0 {
+            return Err(HyprError::IoError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("The workspace id {id} is negative, select it by name instead"),
+            )));
+        }
+    }
+    let sep = if dispatch { " " } else { "," };
+    let string_to_pass = match &cmd {
+        Exec(sh) => format!("exec{sep}{sh}"),
+        Pass(win) => format!("pass{sep}{win}"),
+        KillActiveWindow => "killactive".to_string(),
+        CloseWindow(win) => {
+            format!("closewindow{sep}{win}")
+        }
+        Workspace(work) => format!("workspace{sep}{work}"),
+        FocusWorkspaceOnCurrentMonitor(work) => {
+            format!("focusworkspaceoncurrentmonitor{sep}{work}")
+        }
+        MoveToWorkspace(work, Some(win)) => format!("movetoworkspace{sep}{work},{win}"),
+        MoveToWorkspace(work, None) => format!("movetoworkspace{sep}{work}"),
+        MoveToWorkspaceSilent(work, Some(win)) => {
+            format!("movetoworkspacesilent{sep}{work},{win}")
+        }
+        MoveToWorkspaceSilent(work, None) => format!("movetoworkspacesilent{sep}{work}"),
+        MoveFocusedWindowToWorkspace(work) => {
+            format!("movetoworkspace{sep}{work}",)
+        }
+        MoveFocusedWindowToWorkspaceSilent(work) => {
+            format!("movetoworkspacesilent{sep}{work}",)
+        }
+        ToggleFloating(Some(v)) => format!("togglefloating{sep}{v}"),
+        ToggleFloating(None) => "togglefloating".to_string(),
+        ToggleFullscreen(FullscreenType::NoParam) => "fullscreen".to_string(),
+        ToggleFullscreen(ftype) => format!("fullscreen{sep}{ftype}"),
+        ToggleFakeFullscreen if caps.fullscreen_state => format!("fullscreenstate{sep}-1 2"),
+        ToggleFakeFullscreen => "fakefullscreen".to_string(),
+        ToggleDPMS(stat, Some(mon)) => {
+            format!("dpms{sep}{} {mon}", if *stat { "on" } else { "off" })
+        }
+        ToggleDPMS(stat, None) => format!("dpms{sep}{}", if *stat { "on" } else { "off" }),
+        TogglePseudo => "pseudo".to_string(),
+        TogglePin => "pin".to_string(),
+        MoveFocus(dir) => format!("movefocus{sep}{dir}",),
+        MoveWindow(ident) => format!(
+            "movewindow{sep}{}",
+            match ident {
+                WindowMove::Direction(dir) => dir.to_string(),
+                WindowMove::Monitor(mon) => format!("mon:{mon}"),
+            }
+        ),
+        CenterWindow => "centerwindow".to_string(),
+        ResizeActive(pos) => {
+            format!("resizeactive{sep}{pos}")
+        }
+        MoveActive(pos) => format!("moveactive{sep}{pos}"),
+        ResizeWindowPixel(pos, win) => {
+            format!("resizewindowpixel{sep}{pos},{win}")
+        }
+        MoveWindowPixel(pos, win) => {
+            format!("movewindowpixel{sep}{pos},{win}")
+        }
+        CycleWindow(CycleDirection::Next) => "cyclenext".to_string(),
+        CycleWindow(dir) => format!("cyclenext{sep}{dir}"),
+        SwapWindow(CycleDirection::Next) => "swapnext".to_string(),
+        SwapWindow(dir) => format!("swapnext{sep}{dir}"),
+        FocusWindow(win) => format!("focuswindow{sep}{win}"),
+        FocusMonitor(mon) => format!("focusmonitor{sep}{mon}"),
+        ChangeSplitRatio(ratio) => format!("splitratio{sep}{ratio}"),
+        LayoutMessage(msg) => format!("layoutmsg{sep}{msg}"),
+        ToggleOpaque => "toggleopaque".to_string(),
+        MoveCursorToCorner(corner) => format!("movecursortocorner{sep}{}", corner.clone() as u8),
+
+        WorkspaceOption(opt) => format!("workspaceopt{sep}{opt}"),
+        Exit => "exit".to_string(),
+        ForceRendererReload => "forcerendererreload".to_string(),
+        MoveCurrentWorkspaceToMonitor(mon) => {
+            format!("movecurrentworkspacetomonitor{sep}{mon}")
+        }
+        MoveWorkspaceToMonitor(work, mon) => {
+            format!("moveworkspacetomonitor{sep}{work} {mon}",)
+        }
+        ToggleSpecialWorkspace => "togglespecialworkspace".to_string(),
+        RenameWorkspace(id, name) => {
+            format!(
+                "renameworkspace{sep}{id} {}",
+                name.unwrap_or(&id.to_string())
+            )
+        }
+        SwapActiveWorkspaces(mon, mon2) => format!("swapactiveworkspaces{sep}{mon} {mon2}",),
+        BringActiveToTop => "bringactivetotop".to_string(),
+        SetCursor(theme, size) => {
+            format!("{theme} {}", *size)
+        }
+        FocusUrgentOrLast => "focusurgentorlast".to_string(),
+        ToggleGroup => "togglegroup".to_string(),
+        ChangeGroupActive(active) => format!("changegroupactive{sep}{active}"),
+        MoveIntoGroup(dir) => format!("moveintogroup{sep}{dir}"),
+        MoveOutOfGroup(Some(win)) => format!("moveoutofgroup{sep}{win}"),
+        MoveOutOfGroup(None) => "moveoutofgroup".to_string(),
+        MoveWindowOrGroup(dir) => format!("movewindoworgroup{sep}{dir}"),
+        MoveGroupWindow(dir) => format!("movegroupwindow{sep}{dir}"),
+        LockGroups(lock) => format!("lockgroups{sep}{lock}"),
+        LockActiveGroup(lock) => format!("lockactivegroup{sep}{lock}"),
+        DenyWindowFromGroup(state) => format!("denywindowfromgroup{sep}{state}"),
+        SetIgnoreGroupLock(state) => format!("setignoregrouplock{sep}{state}"),
+        Submap(name) => format!("submap{sep}{name}"),
+        SetProp(win, prop, lock) => format!(
+            "setprop{sep}{win} {prop}{}",
+            if *lock { " lock" } else { "" }
+        ),
+        SetTiled(Some(win)) => format!("settiled{sep}{win}"),
+        SetTiled(None) => "settiled".to_string(),
+        SetFloating(Some(win)) => format!("setfloating{sep}{win}"),
+        SetFloating(None) => "setfloating".to_string(),
+        PinWindow(win) => format!("pin{sep}{win}"),
+        AlterZOrder(zorder, Some(win)) => format!("alterzorder{sep}{zorder},{win}"),
+        AlterZOrder(zorder, None) => format!("alterzorder{sep}{zorder}"),
+        TagWindow(tag, Some(win)) => format!("tagwindow{sep}{tag} {win}"),
+        TagWindow(tag, None) => format!("tagwindow{sep}{tag}"),
+        FullscreenState(internal, client) => format!("fullscreenstate{sep}{internal} {client}"),
+        FocusCurrentOrLast => "focuscurrentorlast".to_string(),
+        MoveCursor(x, y) => format!("movecursor{sep}{x} {y}"),
+        SendShortcut(mods, key, win) => {
+            let mods: Vec<String> = mods
+                .iter()
+                .map(ToString::to_string)
+                .filter(|m| !m.is_empty())
+                .collect();
+            match win {
+                Some(win) => format!("sendshortcut{sep}{},{key},{win}", mods.join(" ")),
+                None => format!("sendshortcut{sep}{},{key}", mods.join(" ")),
+            }
+        }
+        Global(app, name) => format!("global{sep}{app}:{name}"),
+    };
+    if let SetCursor(_, _) = cmd {
+        Ok(format!("setcursor {string_to_pass}"))
+    } else if dispatch {
+        Ok(format!("dispatch {string_to_pass}"))
+    } else {
+        Ok(string_to_pass)
+    }
+}
+
+/// The struct that provides all dispatching methods
+pub struct Dispatch;
+
+impl Dispatch {
+    /// This function calls a specified dispatcher (blocking)
+    ///
+    /// ```rust
+    /// # use hyprland::shared::HResult;
+    /// # fn main() -> HResult<()> {
+    /// use hyprland::dispatch::{DispatchType,Dispatch};
+    /// // This is an example of just one dispatcher, there are many more!
+    /// Dispatch::call(DispatchType::Exec("kitty"))
+    /// # }
+    /// ```
+    pub fn call(dispatch_type: DispatchType) -> HResult<()> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let output = write_to_socket_sync(
+            socket_path,
+            gen_dispatch_str(dispatch_type, true)?.as_bytes(),
+        );
+
+        match output {
+            Ok(msg) => match msg.as_str() {
+                "ok" => Ok(()),
+                msg => Err(HyprError::NotOkDispatch(CommandError::parse(msg))),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// This function calls a specified dispatcher (async)
+    ///
+    /// ```rust
+    /// # use hyprland::shared::HResult;
+    /// # async fn function() -> HResult<()> {
+    /// use hyprland::dispatch::{DispatchType,Dispatch};
+    /// // This is an example of just one dispatcher, there are many more!
+    /// Dispatch::call_async(DispatchType::Exec("kitty")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call_async(dispatch_type: DispatchType<'_>) -> HResult<()> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let output = write_to_socket(
+            socket_path,
+            gen_dispatch_str(dispatch_type, true)?.as_bytes(),
+        )
+        .await;
+
+        match output {
+            Ok(msg) => match msg.as_str() {
+                "ok" => Ok(()),
+                msg => Err(HyprError::NotOkDispatch(CommandError::parse(msg))),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// This function calls several dispatchers in a single request (blocking)
+    ///
+    /// The dispatchers are sent as a `[[BATCH]]`, unless one of them contains a `;`
+    /// (eg. an exec with rules), then they are called one by one
+    pub fn batch(dispatches: &[DispatchType]) -> HResult<()> {
+        if dispatches.is_empty() {
+            return Ok(());
+        }
+        match gen_batch_str(dispatches)? {
+            Some(batch) => {
+                let socket_path = get_socket_path(SocketType::Command);
+                check_batch_reply(&write_to_socket_sync(socket_path, batch.as_bytes())?)
+            }
+            None => dispatches
+                .iter()
+                .try_for_each(|dispatch| Self::call(dispatch.clone())),
+        }
+    }
+
+    /// This function calls several dispatchers in a single request (async)
+    ///
+    /// The dispatchers are sent as a `[[BATCH]]`, unless one of them contains a `;`
+    /// (eg. an exec with rules), then they are called one by one
+    pub async fn batch_async(dispatches: &[DispatchType<'_>]) -> HResult<()> {
+        if dispatches.is_empty() {
+            return Ok(());
+        }
+        match gen_batch_str(dispatches)? {
+            Some(batch) => {
+                let socket_path = get_socket_path(SocketType::Command);
+                check_batch_reply(&write_to_socket(socket_path, batch.as_bytes()).await?)
+            }
+            None => {
+                for dispatch in dispatches {
+                    Self::call_async(dispatch.clone()).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn gen_batch_str(dispatches: &[DispatchType]) -> HResult<Option<String>> {
+    let commands = dispatches
+        .iter()
+        .map(|dispatch| gen_dispatch_str(dispatch.clone(), true))
+        .collect::<HResult<Vec<String>>>()?;
+    if commands.iter().any(|command| command.contains(';')) {
+        return Ok(None);
+    }
+    Ok(Some(format!("[[BATCH]]{}", commands.join(";"))))
+}
+
+/// Every dispatcher of a batch replies, the replies are concatenated
+fn check_batch_reply(reply: &str) -> HResult<()> {
+    let only_ok = |mut line: &str| {
+        while let Some(rest) = line.strip_prefix("ok") {
+            line = rest;
+        }
+        line.is_empty()
+    };
+    let errors: Vec<&str> = reply
+        .lines()
+        .map(str::trim)
+        .filter(|line| !only_ok(line))
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(HyprError::NotOkDispatch(CommandError::parse(
+            errors.join("\n"),
+        )))
+    }
+}
+
+/// Macro abstraction over [Dispatch::call]
+#[macro_export]
+macro_rules! dispatch {
+    ($dis:ident, $( $arg:expr ), *) => {
+        Dispatch::call(DispatchType::$dis($($arg), *))
+    };
+    (async; $dis:ident, $( $arg:expr ), *) => {
+        Dispatch::call_async(DispatchType::$dis($($arg), *))
+    };
+}
+
+#[test]
+fn test_dispatch_wire_format() {
+    let gen = |cmd| match gen_dispatch_str(cmd, true) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let win = || WindowIdentifier::Address(Address::new(0x10));
+    assert_eq!(
+        gen(DispatchType::ResizeActive(Position::Delta(10, -10))),
+        "dispatch resizeactive 10 -10"
+    );
+    assert_eq!(
+        gen(DispatchType::WorkspaceOption(WorkspaceOptions::AllFloat)),
+        "dispatch workspaceopt allfloat"
+    );
+    assert_eq!(
+        gen(DispatchType::WorkspaceOption(WorkspaceOptions::AllPseudo)),
+        "dispatch workspaceopt allpseudo"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveFocusedWindowToWorkspace(
+            WorkspaceIdentifier::Id(4)
+        )),
+        "dispatch movetoworkspace 4"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveFocusedWindowToWorkspaceSilent(
+            WorkspaceIdentifier::Id(4)
+        )),
+        "dispatch movetoworkspacesilent 4"
+    );
+    assert_eq!(
+        gen(DispatchType::ResizeWindowPixel(
+            Position::Exact(640, 480),
+            win()
+        )),
+        "dispatch resizewindowpixel exact 640 480,address:0x10"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveWindowPixel(Position::Delta(-5, 5), win())),
+        "dispatch movewindowpixel -5 5,address:0x10"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveActive(Position::Exact(100, 200))),
+        "dispatch moveactive exact 100 200"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveToWorkspace(
+            WorkspaceIdentifier::Id(3),
+            Some(win())
+        )),
+        "dispatch movetoworkspace 3,address:0x10"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveToWorkspaceSilent(
+            WorkspaceIdentifier::Id(3),
+            Some(win())
+        )),
+        "dispatch movetoworkspacesilent 3,address:0x10"
+    );
+    assert_eq!(
+        gen(DispatchType::ToggleDPMS(false, None)),
+        "dispatch dpms off"
+    );
+    assert_eq!(
+        gen(DispatchType::ToggleDPMS(true, Some("DP-1"))),
+        "dispatch dpms on DP-1"
+    );
+    assert_eq!(
+        gen(DispatchType::ToggleFullscreen(FullscreenType::NoParam)),
+        "dispatch fullscreen"
+    );
+    assert_eq!(
+        gen(DispatchType::CycleWindow(CycleDirection::Next)),
+        "dispatch cyclenext"
+    );
+    assert_eq!(
+        gen(DispatchType::SwapWindow(CycleDirection::Next)),
+        "dispatch swapnext"
+    );
+}
+
+#[test]
+fn test_group_dispatchers() {
+    let gen = |cmd| match gen_dispatch_str(cmd, true) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(
+        gen(DispatchType::ChangeGroupActive(GroupActive::Index(2))),
+        "dispatch changegroupactive 2"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveGroupWindow(GroupDirection::Back)),
+        "dispatch movegroupwindow b"
+    );
+    assert_eq!(
+        gen(DispatchType::LockActiveGroup(LockType::Toggle)),
+        "dispatch lockactivegroup toggle"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveOutOfGroup(Some(
+            WindowIdentifier::ProcessId(42)
+        ))),
+        "dispatch moveoutofgroup pid:42"
+    );
+    assert_eq!(
+        gen(DispatchType::DenyWindowFromGroup(ToggleState::On)),
+        "dispatch denywindowfromgroup on"
+    );
+}
+
+#[test]
+fn test_layout_messages() {
+    let gen = |cmd| match gen_dispatch_str(cmd, true) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(
+        gen(DispatchType::ChangeSplitRatio(SplitRatio::Exact(0.5))),
+        "dispatch splitratio exact 0.5"
+    );
+    assert_eq!(
+        gen(DispatchType::ChangeSplitRatio(SplitRatio::Relative(-0.1))),
+        "dispatch splitratio -0.1"
+    );
+    assert_eq!(
+        gen(DispatchType::ChangeSplitRatio((-0.1).into())),
+        "dispatch splitratio -0.1"
+    );
+    assert_eq!(
+        gen(DispatchType::LayoutMessage(LayoutMessage::PreSelect(
+            Direction::Left
+        ))),
+        "dispatch layoutmsg preselect l"
+    );
+    assert_eq!(
+        gen(DispatchType::LayoutMessage(LayoutMessage::Orientation(
+            MasterOrientation::Center
+        ))),
+        "dispatch layoutmsg orientationcenter"
+    );
+    assert_eq!(
+        gen(DispatchType::LayoutMessage(LayoutMessage::MFact(
+            SplitRatio::Relative(0.05)
+        ))),
+        "dispatch layoutmsg mfact 0.05"
+    );
+}
+
+#[test]
+fn test_window_identifier_parsing() {
+    let parse = |s: &str| match s.parse::<WindowIdentifier>() {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    for selector in [
+        "address:0x55d6ab6a4b50",
+        "pid:1234",
+        "class:^(firefox)$",
+        "initialclass:kitty",
+        "title:.*YouTube.*",
+        "initialtitle:notes",
+        "tag:work",
+        "activewindow",
+        "floating",
+        "tiled",
+    ] {
+        assert_eq!(parse(selector).to_string(), selector);
+    }
+    assert_eq!(
+        parse("^(kitty)$"),
+        WindowIdentifier::ClassRegularExpression("^(kitty)$".to_string())
+    );
+    assert_eq!(
+        parse("title:a:b"),
+        WindowIdentifier::Title("a:b".to_string())
+    );
+    assert!("pid:abc".parse::<WindowIdentifier>().is_err());
+    assert!("address:xyz".parse::<WindowIdentifier>().is_err());
+    assert!("".parse::<WindowIdentifier>().is_err());
+}
+
+#[test]
+fn test_window_state_dispatchers() {
+    let gen = |cmd| match gen_dispatch_str(cmd, true) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(
+        gen(DispatchType::SetProp(
+            WindowIdentifier::ClassRegularExpression("kitty".to_string()),
+            WindowProperty::Alpha(0.8),
+            true
+        )),
+        "dispatch setprop class:kitty alpha 0.8 lock"
+    );
+    assert_eq!(
+        gen(DispatchType::SetProp(
+            WindowIdentifier::ActiveWindow,
+            WindowProperty::NoMaxSize(true),
+            false
+        )),
+        "dispatch setprop activewindow nomaxsize 1"
+    );
+    assert_eq!(
+        gen(DispatchType::AlterZOrder(
+            ZOrder::Top,
+            Some(WindowIdentifier::ProcessId(42))
+        )),
+        "dispatch alterzorder top,pid:42"
+    );
+    assert_eq!(
+        gen(DispatchType::FullscreenState(
+            FullscreenMode::Current,
+            FullscreenMode::Fullscreen
+        )),
+        "dispatch fullscreenstate -1 2"
+    );
+    assert_eq!(
+        gen(DispatchType::MoveCursor(10, 20)),
+        "dispatch movecursor 10 20"
+    );
+}
+
+#[test]
+fn test_dispatch_dry_run() {
+    let recorder = Recorder::start();
+    recorder.respond_with("Invalid dispatcher");
+    assert!(matches!(
+        Dispatch::call(DispatchType::Exec("kitty")),
+        Err(HyprError::NotOkDispatch(err))
+            if err.kind == CommandErrorKind::InvalidDispatcher && err.raw == "Invalid dispatcher"
+    ));
+    assert!(Dispatch::call(DispatchType::Workspace(WorkspaceIdentifier::Id(3))).is_ok());
+    assert_eq!(
+        recorder.commands(),
+        vec!["dispatch exec kitty", "dispatch workspace 3"]
+    );
+}
+
+#[test]
+fn test_dispatch_batch() {
+    let recorder = Recorder::start();
+    let result = Dispatch::batch(&[
+        DispatchType::FocusWindow(WindowIdentifier::Address(Address::from(0x10))),
+        DispatchType::MoveWindowPixel(
+            Position::Exact(10, 20),
+            WindowIdentifier::Address(Address::from(0x10)),
+        ),
+    ]);
+    assert!(result.is_ok());
+    let exec = Exec::new("kitty").rule(ExecRule::Float).rule(ExecRule::Pin);
+    let result = Dispatch::batch(&[
+        DispatchType::Exec(&exec.to_string()),
+        DispatchType::Workspace(WorkspaceIdentifier::Id(2)),
+    ]);
+    assert!(result.is_ok());
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "[[BATCH]]dispatch focuswindow address:0x10;dispatch movewindowpixel exact 10 20,address:0x10",
+            "dispatch exec [float; pin] kitty",
+            "dispatch workspace 2",
+        ]
+    );
+    // an empty batch isn't sent at all
+    recorder.clear();
+    assert!(Dispatch::batch(&[]).is_ok());
+    assert!(recorder.commands().is_empty());
+    assert!(check_batch_reply("ok\n\nok\n\n").is_ok());
+    assert!(check_batch_reply("okok").is_ok());
+    assert!(matches!(
+        check_batch_reply("ok\n\nInvalid dispatcher\n\n"),
+        Err(HyprError::NotOkDispatch(err)) if err.kind == CommandErrorKind::InvalidDispatcher
+    ));
+}
+
+#[test]
+fn test_shortcut_dispatchers() {
+    let gen = |cmd| match gen_dispatch_str(cmd, true) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(
+        gen(DispatchType::SendShortcut(
+            vec![Mod::CTRL, Mod::SHIFT],
+            "T".into(),
+            Some(WindowIdentifier::ClassRegularExpression(
+                "firefox".to_string()
+            ))
+        )),
+        "dispatch sendshortcut CTRL SHIFT,T,class:firefox"
+    );
+    assert_eq!(
+        gen(DispatchType::SendShortcut(
+            vec![Mod::SUPER],
+            ShortcutKey::Code(28),
+            None
+        )),
+        "dispatch sendshortcut SUPER,code:28"
+    );
+    assert_eq!(
+        gen(DispatchType::Global("com.obsproject.Studio", "record")),
+        "dispatch global com.obsproject.Studio:record"
+    );
+}
@@ -0,0 +1,501 @@
+//! Owned versions of the dispatcher types
+//!
+//! [DispatchType] and its identifiers borrow their strings, so they can't be stored,
+//! sent between threads or loaded from config files. The owned types in this module
+//! can, and convert to the borrowed ones with `as_borrowed` for [Dispatch::call].
+//!
+//! ## Schema
+//!
+//! The (de)serialized form is stable, names are in `snake_case`:
+//! - dispatchers are objects with a `dispatcher` name, and their `args`
+//!   (a single value, or an array if there are multiple), `args` is left out if there are none
+//! - identifiers and arguments are externally tagged enums (`{"id": 3}`, `"current"`)
+//! - window identifiers are selector strings (`"class:^(kitty)$"`, `"address:0x..."`)
+//!
+//! ```json
+//! {"dispatcher": "exec", "args": "kitty"}
+//! {"dispatcher": "move_to_workspace_silent", "args": [{"id": 3}, "class:kitty"]}
+//! {"dispatcher": "focus_monitor", "args": {"name": "DP-1"}}
+//! {"dispatcher": "kill_active_window"}
+//! ```
+
+use super::*;
+
+/// Owned version of [MonitorIdentifier]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnedMonitorIdentifier {
+    /// The monitor that is to the specified direction of the active one
+    Direction(Direction),
+    /// The monitor id
+    Id(MonitorId),
+    /// The monitor name
+    Name(String),
+    /// The current monitor
+    Current,
+    /// The workspace relative to the current workspace
+    Relative(i32),
+}
+
+impl OwnedMonitorIdentifier {
+    /// This method returns the borrowed version of the identifier
+    pub fn as_borrowed(&self) -> MonitorIdentifier<'_> {
+        match self {
+            Self::Direction(dir) => MonitorIdentifier::Direction(dir.clone()),
+            Self::Id(id) => MonitorIdentifier::Id(*id),
+            Self::Name(name) => MonitorIdentifier::Name(name),
+            Self::Current => MonitorIdentifier::Current,
+            Self::Relative(int) => MonitorIdentifier::Relative(*int),
+        }
+    }
+}
+
+impl From<MonitorIdentifier<'_>> for OwnedMonitorIdentifier {
+    fn from(mon: MonitorIdentifier<'_>) -> Self {
+        match mon {
+            MonitorIdentifier::Direction(dir) => Self::Direction(dir),
+            MonitorIdentifier::Id(id) => Self::Id(id),
+            MonitorIdentifier::Name(name) => Self::Name(name.to_string()),
+            MonitorIdentifier::Current => Self::Current,
+            MonitorIdentifier::Relative(int) => Self::Relative(int),
+        }
+    }
+}
+
+/// Owned version of [WorkspaceIdentifier]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnedWorkspaceIdentifier {
+    /// The workspace Id, it can't be negative (see [WorkspaceIdentifier::Id])
+    Id(WorkspaceId),
+    /// The workspace relative to the current workspace (`+1`)
+    Relative(i32),
+    /// The workspace on the monitor relative to the current one (`m+1`)
+    RelativeMonitor(i32),
+    /// The workspace on the monitor at a position (`m~1`)
+    AbsoluteMonitor(i32),
+    /// The workspace on the monitor relative to the current one, including empty workspaces (`r+1`)
+    RelativeMonitorIncludingEmpty(i32),
+    /// The workspace on the monitor at a position, including empty workspaces (`r~1`)
+    AbsoluteMonitorIncludingEmpty(i32),
+    /// The open workspace relative to the current one (`e+1`)
+    RelativeOpen(i32),
+    /// The open workspace at a position (`e~1`)
+    AbsoluteOpen(i32),
+    /// The previous Workspace
+    Previous,
+    /// The previous Workspace on the current monitor
+    PreviousPerMonitor,
+    /// The first available empty workspace
+    Empty,
+    /// The first available empty workspace on the current monitor
+    EmptyOnMonitor,
+    /// The next available empty workspace after the current one
+    EmptyNext,
+    /// The name of the workspace
+    Name(String),
+    /// The special workspace
+    Special(Option<String>),
+}
+
+/// This type is the same as [OwnedWorkspaceIdentifier], which now includes the special workspace
+pub type OwnedWorkspaceIdentifierWithSpecial = OwnedWorkspaceIdentifier;
+
+impl OwnedWorkspaceIdentifier {
+    /// This method returns the borrowed version of the identifier
+    pub fn as_borrowed(&self) -> WorkspaceIdentifier<'_> {
+        use OwnedWorkspaceIdentifier::*;
+        match self {
+            Id(id) => WorkspaceIdentifier::Id(*id),
+            Relative(int) => WorkspaceIdentifier::Relative(*int),
+            RelativeMonitor(int) => WorkspaceIdentifier::RelativeMonitor(*int),
+            AbsoluteMonitor(int) => WorkspaceIdentifier::AbsoluteMonitor(*int),
+            RelativeMonitorIncludingEmpty(int) => {
+                WorkspaceIdentifier::RelativeMonitorIncludingEmpty(*int)
+            }
+            AbsoluteMonitorIncludingEmpty(int) => {
+                WorkspaceIdentifier::AbsoluteMonitorIncludingEmpty(*int)
+            }
+            RelativeOpen(int) => WorkspaceIdentifier::RelativeOpen(*int),
+            AbsoluteOpen(int) => WorkspaceIdentifier::AbsoluteOpen(*int),
+            Previous => WorkspaceIdentifier::Previous,
+            PreviousPerMonitor => WorkspaceIdentifier::PreviousPerMonitor,
+            Empty => WorkspaceIdentifier::Empty,
+            EmptyOnMonitor => WorkspaceIdentifier::EmptyOnMonitor,
+            EmptyNext => WorkspaceIdentifier::EmptyNext,
+            Name(name) => WorkspaceIdentifier::Name(name),
+            Special(name) => WorkspaceIdentifier::Special(name.as_deref()),
+        }
+    }
+}
+
+impl From<WorkspaceIdentifier<'_>> for OwnedWorkspaceIdentifier {
+    fn from(work: WorkspaceIdentifier<'_>) -> Self {
+        use WorkspaceIdentifier::*;
+        match work {
+            Id(id) => Self::Id(id),
+            Relative(int) => Self::Relative(int),
+            RelativeMonitor(int) => Self::RelativeMonitor(int),
+            AbsoluteMonitor(int) => Self::AbsoluteMonitor(int),
+            RelativeMonitorIncludingEmpty(int) => Self::RelativeMonitorIncludingEmpty(int),
+            AbsoluteMonitorIncludingEmpty(int) => Self::AbsoluteMonitorIncludingEmpty(int),
+            RelativeOpen(int) => Self::RelativeOpen(int),
+            AbsoluteOpen(int) => Self::AbsoluteOpen(int),
+            Previous => Self::Previous,
+            PreviousPerMonitor => Self::PreviousPerMonitor,
+            Empty => Self::Empty,
+            EmptyOnMonitor => Self::EmptyOnMonitor,
+            EmptyNext => Self::EmptyNext,
+            Name(name) => Self::Name(name.to_string()),
+            Special(name) => Self::Special(name.map(ToString::to_string)),
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedWorkspaceIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_borrowed())
+    }
+}
+
+/// Owned version of [WindowMove]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnedWindowMove {
+    /// Moves the window to a specified monitor
+    Monitor(OwnedMonitorIdentifier),
+    /// Moves the window in a specified direction
+    Direction(Direction),
+}
+
+impl OwnedWindowMove {
+    /// This method returns the borrowed version of the params
+    pub fn as_borrowed(&self) -> WindowMove<'_> {
+        match self {
+            Self::Monitor(mon) => WindowMove::Monitor(mon.as_borrowed()),
+            Self::Direction(dir) => WindowMove::Direction(dir.clone()),
+        }
+    }
+}
+
+impl From<WindowMove<'_>> for OwnedWindowMove {
+    fn from(win: WindowMove<'_>) -> Self {
+        match win {
+            WindowMove::Monitor(mon) => Self::Monitor(mon.into()),
+            WindowMove::Direction(dir) => Self::Direction(dir),
+        }
+    }
+}
+
+/// Owned version of [SubmapName]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnedSubmapName {
+    /// A submap by its name
+    Name(String),
+    /// The default submap (resets the current one)
+    Reset,
+}
+
+impl OwnedSubmapName {
+    /// This method returns the borrowed version of the submap name
+    pub fn as_borrowed(&self) -> SubmapName<'_> {
+        match self {
+            Self::Name(name) => SubmapName::Name(name),
+            Self::Reset => SubmapName::Reset,
+        }
+    }
+}
+
+impl From<SubmapName<'_>> for OwnedSubmapName {
+    fn from(name: SubmapName<'_>) -> Self {
+        match name {
+            SubmapName::Name(name) => Self::Name(name.to_string()),
+            SubmapName::Reset => Self::Reset,
+        }
+    }
+}
+
+/// Owned version of [DispatchType], see the [module docs](self) for its schema
+///
+/// ```rust, no_run
+/// # use hyprland::shared::HResult;
+/// # fn main() -> HResult<()> {
+/// use hyprland::dispatch::{Dispatch, OwnedDispatchType};
+/// let action: OwnedDispatchType =
+///     serde_json::from_str(r#"{"dispatcher": "exec", "args": "kitty"}"#)?;
+/// Dispatch::call(action.as_borrowed())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "dispatcher", content = "args", rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum OwnedDispatchType {
+    SetCursor(String, u16),
+    Exec(String),
+    Pass(WindowIdentifier),
+    KillActiveWindow,
+    CloseWindow(WindowIdentifier),
+    Workspace(OwnedWorkspaceIdentifier),
+    FocusWorkspaceOnCurrentMonitor(OwnedWorkspaceIdentifier),
+    MoveToWorkspace(OwnedWorkspaceIdentifier, Option<WindowIdentifier>),
+    MoveToWorkspaceSilent(OwnedWorkspaceIdentifier, Option<WindowIdentifier>),
+    MoveFocusedWindowToWorkspace(OwnedWorkspaceIdentifier),
+    MoveFocusedWindowToWorkspaceSilent(OwnedWorkspaceIdentifier),
+    ToggleFloating(Option<WindowIdentifier>),
+    ToggleFullscreen(FullscreenType),
+    ToggleFakeFullscreen,
+    #[serde(rename = "toggle_dpms")]
+    ToggleDPMS(bool, Option<String>),
+    TogglePseudo,
+    TogglePin,
+    MoveFocus(Direction),
+    MoveWindow(OwnedWindowMove),
+    CenterWindow,
+    ResizeActive(Position),
+    MoveActive(Position),
+    ResizeWindowPixel(Position, WindowIdentifier),
+    MoveWindowPixel(Position, WindowIdentifier),
+    CycleWindow(CycleDirection),
+    SwapWindow(CycleDirection),
+    FocusWindow(WindowIdentifier),
+    FocusMonitor(OwnedMonitorIdentifier),
+    ChangeSplitRatio(SplitRatio),
+    LayoutMessage(LayoutMessage),
+    ToggleOpaque,
+    MoveCursorToCorner(Corner),
+    WorkspaceOption(WorkspaceOptions),
+    RenameWorkspace(WorkspaceId, Option<String>),
+    Exit,
+    ForceRendererReload,
+    MoveCurrentWorkspaceToMonitor(OwnedMonitorIdentifier),
+    MoveWorkspaceToMonitor(OwnedWorkspaceIdentifier, OwnedMonitorIdentifier),
+    SwapActiveWorkspaces(OwnedMonitorIdentifier, OwnedMonitorIdentifier),
+    BringActiveToTop,
+    ToggleSpecialWorkspace,
+    FocusUrgentOrLast,
+    ToggleGroup,
+    ChangeGroupActive(GroupActive),
+    MoveIntoGroup(Direction),
+    MoveOutOfGroup(Option<WindowIdentifier>),
+    MoveWindowOrGroup(Direction),
+    MoveGroupWindow(GroupDirection),
+    LockGroups(LockType),
+    LockActiveGroup(LockType),
+    DenyWindowFromGroup(ToggleState),
+    SetIgnoreGroupLock(ToggleState),
+    Submap(OwnedSubmapName),
+    SetProp(WindowIdentifier, WindowProperty, bool),
+    SetTiled(Option<WindowIdentifier>),
+    SetFloating(Option<WindowIdentifier>),
+    PinWindow(WindowIdentifier),
+    AlterZOrder(ZOrder, Option<WindowIdentifier>),
+    TagWindow(String, Option<WindowIdentifier>),
+    FullscreenState(FullscreenMode, FullscreenMode),
+    FocusCurrentOrLast,
+    MoveCursor(i32, i32),
+    SendShortcut(Vec<Mod>, ShortcutKey, Option<WindowIdentifier>),
+    Global(String, String),
+}
+
+impl OwnedDispatchType {
+    /// This method returns the borrowed version of the dispatcher, to be used with [Dispatch::call]
+    pub fn as_borrowed(&self) -> DispatchType<'_> {
+        use OwnedDispatchType::*;
+        match self {
+            SetCursor(theme, size) => DispatchType::SetCursor(theme, *size),
+            Exec(cmd) => DispatchType::Exec(cmd),
+            Pass(win) => DispatchType::Pass(win.clone()),
+            KillActiveWindow => DispatchType::KillActiveWindow,
+            CloseWindow(win) => DispatchType::CloseWindow(win.clone()),
+            Workspace(work) => DispatchType::Workspace(work.as_borrowed()),
+            FocusWorkspaceOnCurrentMonitor(work) => {
+                DispatchType::FocusWorkspaceOnCurrentMonitor(work.as_borrowed())
+            }
+            MoveToWorkspace(work, win) => {
+                DispatchType::MoveToWorkspace(work.as_borrowed(), win.clone())
+            }
+            MoveToWorkspaceSilent(work, win) => {
+                DispatchType::MoveToWorkspaceSilent(work.as_borrowed(), win.clone())
+            }
+            MoveFocusedWindowToWorkspace(work) => {
+                DispatchType::MoveFocusedWindowToWorkspace(work.as_borrowed())
+            }
+            MoveFocusedWindowToWorkspaceSilent(work) => {
+                DispatchType::MoveFocusedWindowToWorkspaceSilent(work.as_borrowed())
+            }
+            ToggleFloating(win) => DispatchType::ToggleFloating(win.clone()),
+            ToggleFullscreen(ftype) => DispatchType::ToggleFullscreen(ftype.clone()),
+            ToggleFakeFullscreen => DispatchType::ToggleFakeFullscreen,
+            ToggleDPMS(stat, mon) => DispatchType::ToggleDPMS(*stat, mon.as_deref()),
+            TogglePseudo => DispatchType::TogglePseudo,
+            TogglePin => DispatchType::TogglePin,
+            MoveFocus(dir) => DispatchType::MoveFocus(dir.clone()),
+            MoveWindow(win) => DispatchType::MoveWindow(win.as_borrowed()),
+            CenterWindow => DispatchType::CenterWindow,
+            ResizeActive(pos) => DispatchType::ResizeActive(pos.clone()),
+            MoveActive(pos) => DispatchType::MoveActive(pos.clone()),
+            ResizeWindowPixel(pos, win) => {
+                DispatchType::ResizeWindowPixel(pos.clone(), win.clone())
+            }
+            MoveWindowPixel(pos, win) => DispatchType::MoveWindowPixel(pos.clone(), win.clone()),
+            CycleWindow(dir) => DispatchType::CycleWindow(dir.clone()),
+            SwapWindow(dir) => DispatchType::SwapWindow(dir.clone()),
+            FocusWindow(win) => DispatchType::FocusWindow(win.clone()),
+            FocusMonitor(mon) => DispatchType::FocusMonitor(mon.as_borrowed()),
+            ChangeSplitRatio(ratio) => DispatchType::ChangeSplitRatio(ratio.clone()),
+            LayoutMessage(msg) => DispatchType::LayoutMessage(msg.clone()),
+            ToggleOpaque => DispatchType::ToggleOpaque,
+            MoveCursorToCorner(corner) => DispatchType::MoveCursorToCorner(corner.clone()),
+            WorkspaceOption(opt) => DispatchType::WorkspaceOption(opt.clone()),
+            RenameWorkspace(id, name) => DispatchType::RenameWorkspace(*id, name.as_deref()),
+            Exit => DispatchType::Exit,
+            ForceRendererReload => DispatchType::ForceRendererReload,
+            MoveCurrentWorkspaceToMonitor(mon) => {
+                DispatchType::MoveCurrentWorkspaceToMonitor(mon.as_borrowed())
+            }
+            MoveWorkspaceToMonitor(work, mon) => {
+                DispatchType::MoveWorkspaceToMonitor(work.as_borrowed(), mon.as_borrowed())
+            }
+            SwapActiveWorkspaces(mon, mon2) => {
+                DispatchType::SwapActiveWorkspaces(mon.as_borrowed(), mon2.as_borrowed())
+            }
+            BringActiveToTop => DispatchType::BringActiveToTop,
+            ToggleSpecialWorkspace => DispatchType::ToggleSpecialWorkspace,
+            FocusUrgentOrLast => DispatchType::FocusUrgentOrLast,
+            ToggleGroup => DispatchType::ToggleGroup,
+            ChangeGroupActive(active) => DispatchType::ChangeGroupActive(active.clone()),
+            MoveIntoGroup(dir) => DispatchType::MoveIntoGroup(dir.clone()),
+            MoveOutOfGroup(win) => DispatchType::MoveOutOfGroup(win.clone()),
+            MoveWindowOrGroup(dir) => DispatchType::MoveWindowOrGroup(dir.clone()),
+            MoveGroupWindow(dir) => DispatchType::MoveGroupWindow(dir.clone()),
+            LockGroups(lock) => DispatchType::LockGroups(lock.clone()),
+            LockActiveGroup(lock) => DispatchType::LockActiveGroup(lock.clone()),
+            DenyWindowFromGroup(state) => DispatchType::DenyWindowFromGroup(state.clone()),
+            SetIgnoreGroupLock(state) => DispatchType::SetIgnoreGroupLock(state.clone()),
+            Submap(name) => DispatchType::Submap(name.as_borrowed()),
+            SetProp(win, prop, lock) => DispatchType::SetProp(win.clone(), prop.clone(), *lock),
+            SetTiled(win) => DispatchType::SetTiled(win.clone()),
+            SetFloating(win) => DispatchType::SetFloating(win.clone()),
+            PinWindow(win) => DispatchType::PinWindow(win.clone()),
+            AlterZOrder(zorder, win) => DispatchType::AlterZOrder(zorder.clone(), win.clone()),
+            TagWindow(tag, win) => DispatchType::TagWindow(tag, win.clone()),
+            FullscreenState(internal, client) => DispatchType::FullscreenState(*internal, *client),
+            FocusCurrentOrLast => DispatchType::FocusCurrentOrLast,
+            MoveCursor(x, y) => DispatchType::MoveCursor(*x, *y),
+            SendShortcut(mods, key, win) => {
+                DispatchType::SendShortcut(mods.clone(), key.clone(), win.clone())
+            }
+            Global(app, name) => DispatchType::Global(app, name),
+        }
+    }
+}
+
+impl From<DispatchType<'_>> for OwnedDispatchType {
+    fn from(cmd: DispatchType<'_>) -> Self {
+        use DispatchType::*;
+        match cmd {
+            SetCursor(theme, size) => Self::SetCursor(theme.to_string(), size),
+            Exec(cmd) => Self::Exec(cmd.to_string()),
+            Pass(win) => Self::Pass(win),
+            KillActiveWindow => Self::KillActiveWindow,
+            CloseWindow(win) => Self::CloseWindow(win),
+            Workspace(work) => Self::Workspace(work.into()),
+            FocusWorkspaceOnCurrentMonitor(work) => {
+                Self::FocusWorkspaceOnCurrentMonitor(work.into())
+            }
+            MoveToWorkspace(work, win) => Self::MoveToWorkspace(work.into(), win),
+            MoveToWorkspaceSilent(work, win) => Self::MoveToWorkspaceSilent(work.into(), win),
+            MoveFocusedWindowToWorkspace(work) => Self::MoveFocusedWindowToWorkspace(work.into()),
+            MoveFocusedWindowToWorkspaceSilent(work) => {
+                Self::MoveFocusedWindowToWorkspaceSilent(work.into())
+            }
+            ToggleFloating(win) => Self::ToggleFloating(win),
+            ToggleFullscreen(ftype) => Self::ToggleFullscreen(ftype),
+            ToggleFakeFullscreen => Self::ToggleFakeFullscreen,
+            ToggleDPMS(stat, mon) => Self::ToggleDPMS(stat, mon.map(ToString::to_string)),
+            TogglePseudo => Self::TogglePseudo,
+            TogglePin => Self::TogglePin,
+            MoveFocus(dir) => Self::MoveFocus(dir),
+            MoveWindow(win) => Self::MoveWindow(win.into()),
+            CenterWindow => Self::CenterWindow,
+            ResizeActive(pos) => Self::ResizeActive(pos),
+            MoveActive(pos) => Self::MoveActive(pos),
+            ResizeWindowPixel(pos, win) => Self::ResizeWindowPixel(pos, win),
+            MoveWindowPixel(pos, win) => Self::MoveWindowPixel(pos, win),
+            CycleWindow(dir) => Self::CycleWindow(dir),
+            SwapWindow(dir) => Self::SwapWindow(dir),
+            FocusWindow(win) => Self::FocusWindow(win),
+            FocusMonitor(mon) => Self::FocusMonitor(mon.into()),
+            ChangeSplitRatio(ratio) => Self::ChangeSplitRatio(ratio),
+            LayoutMessage(msg) => Self::LayoutMessage(msg),
+            ToggleOpaque => Self::ToggleOpaque,
+            MoveCursorToCorner(corner) => Self::MoveCursorToCorner(corner),
+            WorkspaceOption(opt) => Self::WorkspaceOption(opt),
+            RenameWorkspace(id, name) => Self::RenameWorkspace(id, name.map(ToString::to_string)),
+            Exit => Self::Exit,
+            ForceRendererReload => Self::ForceRendererReload,
+            MoveCurrentWorkspaceToMonitor(mon) => Self::MoveCurrentWorkspaceToMonitor(mon.into()),
+            MoveWorkspaceToMonitor(work, mon) => {
+                Self::MoveWorkspaceToMonitor(work.into(), mon.into())
+            }
+            SwapActiveWorkspaces(mon, mon2) => Self::SwapActiveWorkspaces(mon.into(), mon2.into()),
+            BringActiveToTop => Self::BringActiveToTop,
+            ToggleSpecialWorkspace => Self::ToggleSpecialWorkspace,
+            FocusUrgentOrLast => Self::FocusUrgentOrLast,
+            ToggleGroup => Self::ToggleGroup,
+            ChangeGroupActive(active) => Self::ChangeGroupActive(active),
+            MoveIntoGroup(dir) => Self::MoveIntoGroup(dir),
+            MoveOutOfGroup(win) => Self::MoveOutOfGroup(win),
+            MoveWindowOrGroup(dir) => Self::MoveWindowOrGroup(dir),
+            MoveGroupWindow(dir) => Self::MoveGroupWindow(dir),
+            LockGroups(lock) => Self::LockGroups(lock),
+            LockActiveGroup(lock) => Self::LockActiveGroup(lock),
+            DenyWindowFromGroup(state) => Self::DenyWindowFromGroup(state),
+            SetIgnoreGroupLock(state) => Self::SetIgnoreGroupLock(state),
+            Submap(name) => Self::Submap(name.into()),
+            SetProp(win, prop, lock) => Self::SetProp(win, prop, lock),
+            SetTiled(win) => Self::SetTiled(win),
+            SetFloating(win) => Self::SetFloating(win),
+            PinWindow(win) => Self::PinWindow(win),
+            AlterZOrder(zorder, win) => Self::AlterZOrder(zorder, win),
+            TagWindow(tag, win) => Self::TagWindow(tag.to_string(), win),
+            FullscreenState(internal, client) => Self::FullscreenState(internal, client),
+            FocusCurrentOrLast => Self::FocusCurrentOrLast,
+            MoveCursor(x, y) => Self::MoveCursor(x, y),
+            SendShortcut(mods, key, win) => Self::SendShortcut(mods, key, win),
+            Global(app, name) => Self::Global(app.to_string(), name.to_string()),
+        }
+    }
+}
+
+#[test]
+fn test_owned_dispatch_serde() {
+    let json = r#"{"dispatcher":"move_to_workspace_silent","args":[{"id":3},"class:kitty"]}"#;
+    let owned: OwnedDispatchType = match serde_json::from_str(json) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(
+        owned.as_borrowed(),
+        DispatchType::MoveToWorkspaceSilent(
+            WorkspaceIdentifier::Id(3),
+            Some(WindowIdentifier::ClassRegularExpression(
+                "kitty".to_string()
+            ))
+        )
+    );
+    assert!(matches!(serde_json::to_string(&owned), Ok(v) if v == json));
+
+    let borrowed = DispatchType::FocusMonitor(MonitorIdentifier::Name("DP-1"));
+    let owned = OwnedDispatchType::from(borrowed.clone());
+    assert_eq!(owned.as_borrowed(), borrowed);
+    assert!(matches!(
+        serde_json::to_string(&owned),
+        Ok(v) if v == r#"{"dispatcher":"focus_monitor","args":{"name":"DP-1"}}"#
+    ));
+    assert!(matches!(
+        serde_json::to_string(&OwnedDispatchType::KillActiveWindow),
+        Ok(v) if v == r#"{"dispatcher":"kill_active_window"}"#
+    ));
+}
@@ -0,0 +1,1064 @@
+//! Parsing of dispatcher strings
+//!
+//! This is the inverse of the strings sent to Hyprland, both the `hyprctl dispatch` form
+//! (`movetoworkspacesilent 3,address:0x...`, optionally prefixed with `dispatch`) and the
+//! form used in bind lines (`movetoworkspacesilent, 3,address:0x...`) are accepted
+
+use super::*;
+use std::str::FromStr;
+
+fn parse_arg<T: FromStr>(arg: &str, what: &str) -> HResult<T> {
+    arg.trim()
+        .parse()
+        .map_err(|_| HyprError::ParseError(format!("{arg:?} is not a valid {what}")))
+}
+
+fn split_arg<'a>(args: &'a str, sep: char, what: &str) -> HResult<(&'a str, &'a str)> {
+    match args.split_once(sep) {
+        Some((first, second)) => Ok((first.trim(), second.trim())),
+        None => Err(HyprError::ParseError(format!(
+            "{args:?} is missing the {what}"
+        ))),
+    }
+}
+
+fn parse_relative(arg: &str) -> Option<i32> {
+    if arg.starts_with('+') || arg.starts_with('-') {
+        arg.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn parse_position(arg: &str) -> HResult<Position> {
+    let (exact, arg) = match arg.trim().strip_prefix("exact ") {
+        Some(arg) => (true, arg),
+        None => (false, arg),
+    };
+    let (x, y) = split_arg(arg.trim(), ' ', "second coordinate")?;
+    let (x, y) = (parse_arg(x, "coordinate")?, parse_arg(y, "coordinate")?);
+    Ok(if exact {
+        Position::Exact(x, y)
+    } else {
+        Position::Delta(x, y)
+    })
+}
+
+impl<'a> TryFrom<&'a str> for MonitorIdentifier<'a> {
+    type Error = HyprError;
+
+    fn try_from(s: &'a str) -> HResult<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(HyprError::ParseError(
+                "A monitor identifier can't be empty".to_string(),
+            ));
+        }
+        Ok(if s == "current" {
+            MonitorIdentifier::Current
+        } else if let Ok(dir) = s.parse() {
+            MonitorIdentifier::Direction(dir)
+        } else if let Some(int) = parse_relative(s) {
+            MonitorIdentifier::Relative(int)
+        } else if let Ok(id) = s.parse() {
+            MonitorIdentifier::Id(id)
+        } else {
+            MonitorIdentifier::Name(s)
+        })
+    }
+}
+
+impl<'a> WorkspaceIdentifier<'a> {
+    /// This method parses a workspace selector, borrowing from it
+    ///
+    /// ```rust
+    /// # use hyprland::shared::HResult;
+    /// # fn main() -> HResult<()> {
+    /// use hyprland::dispatch::WorkspaceIdentifier;
+    /// assert_eq!(
+    ///     WorkspaceIdentifier::parse("m~2")?,
+    ///     WorkspaceIdentifier::AbsoluteMonitor(2)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse(s: &'a str) -> HResult<Self> {
+        Self::try_from(s)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for WorkspaceIdentifier<'a> {
+    type Error = HyprError;
+
+    fn try_from(s: &'a str) -> HResult<Self> {
+        use WorkspaceIdentifier::*;
+        let s = s.trim();
+        let absolute = |arg: &str| arg.strip_prefix('~').and_then(|int| int.parse().ok());
+        Ok(match s {
+            "previous" => Previous,
+            "previous_per_monitor" => PreviousPerMonitor,
+            "empty" => Empty,
+            "emptym" => EmptyOnMonitor,
+            "emptyn" => EmptyNext,
+            "special" => Special(None),
+            _ => {
+                if let Some(name) = s.strip_prefix("special:") {
+                    Special(Some(name))
+                } else if let Some(name) = s.strip_prefix("name:") {
+                    Name(name)
+                } else if let Some(int) = s.strip_prefix('m').and_then(parse_relative) {
+                    RelativeMonitor(int)
+                } else if let Some(int) = s.strip_prefix('m').and_then(absolute) {
+                    AbsoluteMonitor(int)
+                } else if let Some(int) = s.strip_prefix('r').and_then(parse_relative) {
+                    RelativeMonitorIncludingEmpty(int)
+                } else if let Some(int) = s.strip_prefix('r').and_then(absolute) {
+                    AbsoluteMonitorIncludingEmpty(int)
+                } else if let Some(int) = s.strip_prefix('e').and_then(parse_relative) {
+                    RelativeOpen(int)
+                } else if let Some(int) = s.strip_prefix('e').and_then(absolute) {
+                    AbsoluteOpen(int)
+                } else if let Some(int) = parse_relative(s) {
+                    Relative(int)
+                } else {
+                    Id(parse_arg(s, "workspace identifier")?)
+                }
+            }
+        })
+    }
+}
+
+impl FromStr for OwnedWorkspaceIdentifier {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(WorkspaceIdentifier::parse(s)?.into())
+    }
+}
+
+fn parse_flag(arg: &str) -> HResult<bool> {
+    match arg.trim() {
+        "1" | "on" | "true" => Ok(true),
+        "0" | "off" | "false" => Ok(false),
+        _ => Err(HyprError::ParseError(format!(
+            "{arg:?} is not a valid flag"
+        ))),
+    }
+}
+
+impl FromStr for WindowProperty {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use WindowProperty::*;
+        let (prop, value) = split_arg(s.trim(), ' ', "property value")?;
+        let size = || -> HResult<(u16, u16)> {
+            let (w, h) = split_arg(value, ' ', "height")?;
+            Ok((parse_arg(w, "width")?, parse_arg(h, "height")?))
+        };
+        Ok(match prop {
+            "alpha" => Alpha(parse_arg(value, "opacity")?),
+            "alphainactive" => AlphaInactive(parse_arg(value, "opacity")?),
+            "rounding" => Rounding(parse_arg(value, "rounding")?),
+            "bordersize" => BorderSize(parse_arg(value, "border size")?),
+            "bordercolor" => BorderColor(value.to_string()),
+            "maxsize" => {
+                let (w, h) = size()?;
+                MaxSize(w, h)
+            }
+            "minsize" => {
+                let (w, h) = size()?;
+                MinSize(w, h)
+            }
+            "nomaxsize" => NoMaxSize(parse_flag(value)?),
+            "forcenoblur" => ForceNoBlur(parse_flag(value)?),
+            "forceopaque" => ForceOpaque(parse_flag(value)?),
+            "forcenoanims" => ForceNoAnims(parse_flag(value)?),
+            "forcenoborder" => ForceNoBorder(parse_flag(value)?),
+            "forcenoshadow" => ForceNoShadow(parse_flag(value)?),
+            "forcenodim" => ForceNoDim(parse_flag(value)?),
+            "nofocus" => NoFocus(parse_flag(value)?),
+            "keepaspectratio" => KeepAspectRatio(parse_flag(value)?),
+            "windowdance" => WindowDanceCompat(parse_flag(value)?),
+            _ => {
+                return Err(HyprError::ParseError(format!(
+                    "{prop:?} is not a valid window property"
+                )))
+            }
+        })
+    }
+}
+
+impl FromStr for FullscreenMode {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match parse_arg(s, "fullscreen mode")? {
+            -1i8 => FullscreenMode::Current,
+            0 => FullscreenMode::None,
+            1 => FullscreenMode::Maximized,
+            2 => FullscreenMode::Fullscreen,
+            3 => FullscreenMode::MaximizedFullscreen,
+            _ => {
+                return Err(HyprError::ParseError(format!(
+                    "{s:?} is not a valid fullscreen mode"
+                )))
+            }
+        })
+    }
+}
+
+impl FromStr for Mod {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_uppercase().as_str() {
+            "SUPER" | "WIN" | "LOGO" | "MOD4" => Mod::SUPER,
+            "SHIFT" => Mod::SHIFT,
+            "ALT" | "MOD1" => Mod::ALT,
+            "CTRL" | "CONTROL" => Mod::CTRL,
+            "" | "NONE" => Mod::NONE,
+            _ => return Err(HyprError::ParseError(format!("{s:?} is not a valid mod"))),
+        })
+    }
+}
+
+impl FromStr for ShortcutKey {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(if let Some(code) = s.strip_prefix("code:") {
+            ShortcutKey::Code(parse_arg(code, "keycode")?)
+        } else if let Some(button) = s.strip_prefix("mouse:") {
+            ShortcutKey::Mouse(parse_arg(button, "mouse button")?)
+        } else if s.is_empty() {
+            return Err(HyprError::ParseError("A key can't be empty".to_string()));
+        } else {
+            ShortcutKey::Key(s.to_string())
+        })
+    }
+}
+
+impl FromStr for SplitRatio {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().strip_prefix("exact ") {
+            Some(ratio) => SplitRatio::Exact(parse_arg(ratio, "split ratio")?),
+            None => SplitRatio::Relative(parse_arg(s, "split ratio")?),
+        })
+    }
+}
+
+impl FromStr for GroupActive {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "f" => GroupActive::Forward,
+            "b" => GroupActive::Back,
+            index => GroupActive::Index(parse_arg(index, "group index")?),
+        })
+    }
+}
+
+impl FromStr for LayoutMessage {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use LayoutMessage::*;
+        let s = s.trim();
+        let (msg, args) = match s.split_once(' ') {
+            Some((msg, args)) => (msg, args.trim()),
+            None => (s, ""),
+        };
+        Ok(match (msg, args) {
+            ("togglesplit", "") => ToggleSplit,
+            ("swapsplit", "") => SwapSplit,
+            ("preselect", dir) => PreSelect(parse_arg(dir, "direction")?),
+            ("swapwithmaster", "") => SwapWithMaster,
+            ("focusmaster", "") => FocusMaster,
+            ("cyclenext", "") => CycleNext,
+            ("cycleprev", "") => CyclePrev,
+            ("addmaster", "") => AddMaster,
+            ("removemaster", "") => RemoveMaster,
+            ("orientationnext", "") => OrientationNext,
+            ("orientationprev", "") => OrientationPrev,
+            ("mfact", ratio) => MFact(ratio.parse()?),
+            (msg, "") if msg.starts_with("orientation") => {
+                Orientation(parse_arg(&msg["orientation".len()..], "orientation")?)
+            }
+            _ => {
+                return Err(HyprError::ParseError(format!(
+                    "{s:?} is not a valid layout message"
+                )))
+            }
+        })
+    }
+}
+
+impl<'a> DispatchType<'a> {
+    /// This method parses a dispatcher string, borrowing from it
+    ///
+    /// Both the `hyprctl dispatch` form and the form used in bind lines are accepted
+    ///
+    /// ```rust
+    /// # use hyprland::shared::HResult;
+    /// # fn main() -> HResult<()> {
+    /// use hyprland::dispatch::{DispatchType, WorkspaceIdentifier};
+    /// assert_eq!(
+    ///     DispatchType::parse("movetoworkspacesilent, 3")?,
+    ///     DispatchType::MoveToWorkspaceSilent(WorkspaceIdentifier::Id(3), None)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse(s: &'a str) -> HResult<Self> {
+        Self::try_from(s)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for DispatchType<'a> {
+    type Error = HyprError;
+
+    fn try_from(s: &'a str) -> HResult<Self> {
+        use DispatchType::*;
+        let s = s.trim();
+        let s = s.strip_prefix("dispatch ").unwrap_or(s).trim_start();
+        let (name, args) = match s.find([' ', ',']) {
+            Some(index) => (&s[..index], s[index + 1..].trim()),
+            None => (s, ""),
+        };
+        let opt_win = || -> HResult<Option<WindowIdentifier>> {
+            if args.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(args.parse()?))
+            }
+        };
+        let move_to_workspace =
+            || -> HResult<(WorkspaceIdentifier<'a>, Option<WindowIdentifier>)> {
+                Ok(match args.split_once(',') {
+                    Some((work, win)) => (work.try_into()?, Some(win.parse()?)),
+                    None => (args.try_into()?, None),
+                })
+            };
+
+        let dispatcher = match name {
+            "setcursor" => {
+                let (theme, size) = split_arg(args, ' ', "cursor size")?;
+                SetCursor(theme, parse_arg(size, "cursor size")?)
+            }
+            "exec" if !args.is_empty() => Exec(args),
+            "pass" => Pass(args.parse()?),
+            "killactive" => KillActiveWindow,
+            "closewindow" => CloseWindow(args.parse()?),
+            "workspace" => Workspace(args.try_into()?),
+            "focusworkspaceoncurrentmonitor" => FocusWorkspaceOnCurrentMonitor(args.try_into()?),
+            "movetoworkspace" => {
+                let (work, win) = move_to_workspace()?;
+                MoveToWorkspace(work, win)
+            }
+            "movetoworkspacesilent" => {
+                let (work, win) = move_to_workspace()?;
+                MoveToWorkspaceSilent(work, win)
+            }
+            "togglefloating" => ToggleFloating(opt_win()?),
+            "fullscreen" => ToggleFullscreen(parse_arg(args, "fullscreen type")?),
+            "fakefullscreen" => ToggleFakeFullscreen,
+            "fullscreenstate" => {
+                let (internal, client) = split_arg(args, ' ', "client fullscreen mode")?;
+                FullscreenState(internal.parse()?, client.parse()?)
+            }
+            "dpms" => {
+                let (stat, mon) = match args.split_once(' ') {
+                    Some((stat, mon)) => (stat, Some(mon.trim())),
+                    None => (args, None),
+                };
+                match stat {
+                    "on" => ToggleDPMS(true, mon),
+                    "off" => ToggleDPMS(false, mon),
+                    _ => {
+                        return Err(HyprError::ParseError(format!(
+                            "{stat:?} is not a valid dpms state"
+                        )))
+                    }
+                }
+            }
+            "pseudo" => TogglePseudo,
+            "pin" if args.is_empty() => TogglePin,
+            "pin" => PinWindow(args.parse()?),
+            "movefocus" => MoveFocus(parse_arg(args, "direction")?),
+            "movewindow" => MoveWindow(match args.strip_prefix("mon:") {
+                Some(mon) => WindowMove::Monitor(mon.try_into()?),
+                None => WindowMove::Direction(parse_arg(args, "direction")?),
+            }),
+            "centerwindow" => CenterWindow,
+            "resizeactive" => ResizeActive(parse_position(args)?),
+            "moveactive" => MoveActive(parse_position(args)?),
+            "resizewindowpixel" => {
+                let (pos, win) = split_arg(args, ',', "window")?;
+                ResizeWindowPixel(parse_position(pos)?, win.parse()?)
+            }
+            "movewindowpixel" => {
+                let (pos, win) = split_arg(args, ',', "window")?;
+                MoveWindowPixel(parse_position(pos)?, win.parse()?)
+            }
+            "cyclenext" => CycleWindow(parse_arg(args, "cycle direction")?),
+            "swapnext" => SwapWindow(parse_arg(args, "cycle direction")?),
+            "focuswindow" => FocusWindow(args.parse()?),
+            "focusmonitor" => FocusMonitor(args.try_into()?),
+            "splitratio" => ChangeSplitRatio(args.parse()?),
+            "layoutmsg" => LayoutMessage(args.parse()?),
+            "toggleopaque" => ToggleOpaque,
+            "movecursortocorner" => MoveCursorToCorner(match parse_arg(args, "corner")? {
+                0u8 => Corner::TopRight,
+                1 => Corner::TopLeft,
+                2 => Corner::BottomRight,
+                3 => Corner::BottomLeft,
+                _ => {
+                    return Err(HyprError::ParseError(format!(
+                        "{args:?} is not a valid corner"
+                    )))
+                }
+            }),
+            "workspaceopt" => WorkspaceOption(parse_arg(args, "workspace option")?),
+            "renameworkspace" => {
+                let (id, name) = match args.split_once(' ') {
+                    Some((id, name)) => (id, Some(name.trim())),
+                    None => (args, None),
+                };
+                let id = parse_arg(id, "workspace id")?;
+                RenameWorkspace(id, name.filter(|name| *name != id.to_string()))
+            }
+            "exit" => Exit,
+            "forcerendererreload" => ForceRendererReload,
+            "movecurrentworkspacetomonitor" => MoveCurrentWorkspaceToMonitor(args.try_into()?),
+            "moveworkspacetomonitor" => {
+                // workspace names can contain spaces, monitor names can't
+                let (work, mon) = args.rsplit_once(' ').ok_or_else(|| {
+                    HyprError::ParseError(format!("{args:?} is missing the monitor"))
+                })?;
+                MoveWorkspaceToMonitor(work.try_into()?, mon.try_into()?)
+            }
+            "swapactiveworkspaces" => {
+                let (mon, mon2) = split_arg(args, ' ', "second monitor")?;
+                SwapActiveWorkspaces(mon.try_into()?, mon2.try_into()?)
+            }
+            "bringactivetotop" => BringActiveToTop,
+            "togglespecialworkspace" => ToggleSpecialWorkspace,
+            "focusurgentorlast" => FocusUrgentOrLast,
+            "togglegroup" => ToggleGroup,
+            "changegroupactive" => ChangeGroupActive(args.parse()?),
+            "moveintogroup" => MoveIntoGroup(parse_arg(args, "direction")?),
+            "moveoutofgroup" => MoveOutOfGroup(opt_win()?),
+            "movewindoworgroup" => MoveWindowOrGroup(parse_arg(args, "direction")?),
+            "movegroupwindow" => MoveGroupWindow(parse_arg(args, "group direction")?),
+            "lockgroups" => LockGroups(parse_arg(args, "lock type")?),
+            "lockactivegroup" => LockActiveGroup(parse_arg(args, "lock type")?),
+            "denywindowfromgroup" => DenyWindowFromGroup(parse_arg(args, "toggle state")?),
+            "setignoregrouplock" => SetIgnoreGroupLock(parse_arg(args, "toggle state")?),
+            "submap" => Submap(match args {
+                "reset" => SubmapName::Reset,
+                "" => {
+                    return Err(HyprError::ParseError(
+                        "A submap name can't be empty".to_string(),
+                    ))
+                }
+                name => SubmapName::Name(name),
+            }),
+            "setprop" => {
+                let (win, prop) = split_arg(args, ' ', "property")?;
+                match prop.strip_suffix(" lock") {
+                    Some(prop) => SetProp(win.parse()?, prop.parse()?, true),
+                    None => SetProp(win.parse()?, prop.parse()?, false),
+                }
+            }
+            "settiled" => SetTiled(opt_win()?),
+            "setfloating" => SetFloating(opt_win()?),
+            "alterzorder" => match args.split_once(',') {
+                Some((zorder, win)) => {
+                    AlterZOrder(parse_arg(zorder, "z order")?, Some(win.parse()?))
+                }
+                None => AlterZOrder(parse_arg(args, "z order")?, None),
+            },
+            "tagwindow" if !args.is_empty() => match args.split_once(' ') {
+                Some((tag, win)) => TagWindow(tag, Some(win.parse()?)),
+                None => TagWindow(args, None),
+            },
+            "focuscurrentorlast" => FocusCurrentOrLast,
+            "movecursor" => {
+                let (x, y) = split_arg(args, ' ', "y coordinate")?;
+                MoveCursor(parse_arg(x, "coordinate")?, parse_arg(y, "coordinate")?)
+            }
+            "sendshortcut" => {
+                let (mods, rest) = split_arg(args, ',', "key")?;
+                let (key, win) = match rest.split_once(',') {
+                    Some((key, win)) => (key, Some(win.parse()?)),
+                    None => (rest, None),
+                };
+                let mods = mods
+                    .split([' ', '_'])
+                    .filter(|m| !m.is_empty())
+                    .map(str::parse)
+                    .collect::<HResult<_>>()?;
+                SendShortcut(mods, key.parse()?, win)
+            }
+            "global" => {
+                let (app, name) = split_arg(args, ':', "shortcut name")?;
+                Global(app, name)
+            }
+            _ => {
+                return Err(HyprError::ParseError(format!(
+                    "{s:?} is not a valid dispatcher"
+                )))
+            }
+        };
+
+        let takes_args = !matches!(
+            dispatcher,
+            KillActiveWindow
+                | ToggleFakeFullscreen
+                | TogglePseudo
+                | TogglePin
+                | CenterWindow
+                | ToggleOpaque
+                | Exit
+                | ForceRendererReload
+                | BringActiveToTop
+                | ToggleSpecialWorkspace
+                | FocusUrgentOrLast
+                | ToggleGroup
+                | FocusCurrentOrLast
+        );
+        if !takes_args && !args.is_empty() {
+            return Err(HyprError::ParseError(format!(
+                "The {name} dispatcher doesn't take arguments, got {args:?}"
+            )));
+        }
+        Ok(dispatcher)
+    }
+}
+
+impl FromStr for OwnedDispatchType {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(DispatchType::parse(s)?.into())
+    }
+}
+
+#[test]
+fn test_dispatch_round_trip() {
+    use DispatchType::*;
+    let win = || WindowIdentifier::Address(Address::new(0x55d6ab6a4b50));
+    let dispatchers = vec![
+        SetCursor("Bibata-Modern-Ice", 24),
+        Exec("kitty --class term"),
+        Pass(WindowIdentifier::ClassRegularExpression(
+            "^(obs)$".to_string(),
+        )),
+        KillActiveWindow,
+        CloseWindow(WindowIdentifier::Title("Picture-in-Picture".to_string())),
+        Workspace(WorkspaceIdentifier::Special(Some("term"))),
+        Workspace(WorkspaceIdentifier::RelativeMonitor(-1)),
+        FocusWorkspaceOnCurrentMonitor(WorkspaceIdentifier::Id(3)),
+        MoveToWorkspace(WorkspaceIdentifier::Name("web"), Some(win())),
+        MoveToWorkspace(WorkspaceIdentifier::Previous, None),
+        MoveToWorkspace(WorkspaceIdentifier::Special(Some("term")), None),
+        MoveToWorkspaceSilent(WorkspaceIdentifier::AbsoluteMonitor(2), Some(win())),
+        MoveToWorkspaceSilent(WorkspaceIdentifier::Id(3), Some(win())),
+        MoveToWorkspaceSilent(WorkspaceIdentifier::RelativeOpen(2), None),
+        MoveFocusedWindowToWorkspace(WorkspaceIdentifier::Id(4)),
+        MoveFocusedWindowToWorkspaceSilent(WorkspaceIdentifier::Empty),
+        ToggleFloating(Some(WindowIdentifier::ProcessId(42))),
+        ToggleFloating(None),
+        ToggleFullscreen(FullscreenType::Maximize),
+        ToggleFullscreen(FullscreenType::NoParam),
+        ToggleFakeFullscreen,
+        ToggleDPMS(true, Some("DP-1")),
+        ToggleDPMS(false, None),
+        TogglePseudo,
+        TogglePin,
+        MoveFocus(Direction::Up),
+        MoveWindow(WindowMove::Monitor(MonitorIdentifier::Name("HDMI-A-1"))),
+        MoveWindow(WindowMove::Direction(Direction::Left)),
+        CenterWindow,
+        ResizeActive(Position::Delta(10, -10)),
+        MoveActive(Position::Exact(100, 200)),
+        ResizeWindowPixel(Position::Exact(640, 480), win()),
+        MoveWindowPixel(Position::Delta(-5, 5), WindowIdentifier::Floating),
+        CycleWindow(CycleDirection::Next),
+        SwapWindow(CycleDirection::Previous),
+        FocusWindow(WindowIdentifier::InitialClass("kitty".to_string())),
+        FocusMonitor(MonitorIdentifier::Id(1)),
+        FocusMonitor(MonitorIdentifier::Relative(1)),
+        ChangeSplitRatio(SplitRatio::Exact(0.5)),
+        LayoutMessage(super::LayoutMessage::Orientation(MasterOrientation::Top)),
+        LayoutMessage(super::LayoutMessage::MFact(SplitRatio::Relative(-0.05))),
+        ToggleOpaque,
+        MoveCursorToCorner(Corner::BottomLeft),
+        WorkspaceOption(WorkspaceOptions::AllFloat),
+        RenameWorkspace(2, Some("code")),
+        RenameWorkspace(2, None),
+        Exit,
+        ForceRendererReload,
+        MoveCurrentWorkspaceToMonitor(MonitorIdentifier::Direction(Direction::Right)),
+        MoveWorkspaceToMonitor(WorkspaceIdentifier::Id(5), MonitorIdentifier::Current),
+        SwapActiveWorkspaces(MonitorIdentifier::Name("DP-1"), MonitorIdentifier::Id(0)),
+        BringActiveToTop,
+        ToggleSpecialWorkspace,
+        FocusUrgentOrLast,
+        ToggleGroup,
+        ChangeGroupActive(GroupActive::Index(3)),
+        MoveIntoGroup(Direction::Down),
+        MoveOutOfGroup(None),
+        MoveWindowOrGroup(Direction::Right),
+        MoveGroupWindow(GroupDirection::Forward),
+        LockGroups(LockType::Lock),
+        LockActiveGroup(LockType::Toggle),
+        DenyWindowFromGroup(ToggleState::Off),
+        SetIgnoreGroupLock(ToggleState::Toggle),
+        Submap(SubmapName::Name("resize")),
+        Submap(SubmapName::Reset),
+        SetProp(
+            WindowIdentifier::ClassRegularExpression("kitty".to_string()),
+            WindowProperty::Alpha(0.8),
+            true,
+        ),
+        SetProp(win(), WindowProperty::ForceNoBlur(true), false),
+        SetProp(
+            WindowIdentifier::ActiveWindow,
+            WindowProperty::BorderColor("rgba(33ccffee) rgba(00ff99ee) 45deg".to_string()),
+            true,
+        ),
+        SetProp(win(), WindowProperty::MaxSize(1280, 720), false),
+        SetTiled(None),
+        SetFloating(Some(win())),
+        PinWindow(WindowIdentifier::Title("Picture-in-Picture".to_string())),
+        AlterZOrder(ZOrder::Top, None),
+        AlterZOrder(ZOrder::Bottom, Some(win())),
+        TagWindow("+code", None),
+        TagWindow("-code", Some(win())),
+        FullscreenState(FullscreenMode::Maximized, FullscreenMode::None),
+        FocusCurrentOrLast,
+        MoveCursor(640, -20),
+        SendShortcut(
+            vec![Mod::CTRL, Mod::SHIFT],
+            ShortcutKey::Key("T".to_string()),
+            Some(WindowIdentifier::ClassRegularExpression(
+                "firefox".to_string(),
+            )),
+        ),
+        SendShortcut(vec![], ShortcutKey::Mouse(272), None),
+        Global("com.obsproject.Studio", "record"),
+    ];
+    for dispatch in [true, false] {
+        for cmd in &dispatchers {
+            let string = match gen_dispatch_str(cmd.clone(), dispatch) {
+                Ok(v) => v,
+                Err(e) => panic!("Error occured: {e}"),
+            };
+            let parsed = match DispatchType::parse(&string) {
+                Ok(v) => v,
+                Err(e) => panic!("Error occured parsing {string:?}: {e}"),
+            };
+            assert!(matches!(gen_dispatch_str(parsed.clone(), dispatch), Ok(v) if v == string));
+            // these are aliases of other dispatchers on the wire
+            if !matches!(
+                cmd,
+                MoveFocusedWindowToWorkspace(_)
+                    | MoveFocusedWindowToWorkspaceSilent(_)
+                    | ToggleFakeFullscreen
+            ) {
+                assert_eq!(&parsed, cmd);
+            }
+        }
+    }
+
+    assert!(matches!(
+        "movetoworkspacesilent 3,address:0x55d6ab6a4b50".parse::<OwnedDispatchType>(),
+        Ok(OwnedDispatchType::MoveToWorkspaceSilent(
+            OwnedWorkspaceIdentifier::Id(3),
+            Some(_)
+        ))
+    ));
+    assert!(matches!(
+        DispatchType::parse("workspace, name:web"),
+        Ok(Workspace(WorkspaceIdentifier::Name("web")))
+    ));
+    assert!(DispatchType::parse("notadispatcher 1").is_err());
+    assert!(DispatchType::parse("movefocus x").is_err());
+    assert!(DispatchType::parse("killactive now").is_err());
+    assert!(DispatchType::parse("movetoworkspace").is_err());
+}
+
+#[test]
+fn test_dispatch_round_trip_generated() {
+    use quickcheck::{Arbitrary, Gen, QuickCheck, TestResult};
+
+    /// The number of [OwnedDispatchType] variants the generator covers
+    const VARIANTS: usize = 64;
+
+    fn pick<T: Clone>(g: &mut Gen, items: &[T]) -> T {
+        items[usize::arbitrary(g) % items.len()].clone()
+    }
+    // names are kept apart from keywords (`current`, `reset`, directions...) and separators,
+    // those are covered by the hand-written cases
+    fn name(g: &mut Gen) -> String {
+        let prefix = pick(g, &["kitty", "web", "DP-", "term_", "Foo"]);
+        format!("{prefix}{}", u8::arbitrary(g))
+    }
+    fn opt<T>(g: &mut Gen, f: impl FnOnce(&mut Gen) -> T) -> Option<T> {
+        bool::arbitrary(g).then(|| f(g))
+    }
+    fn ratio(g: &mut Gen) -> f32 {
+        f32::from(i16::arbitrary(g)) / 100.0
+    }
+    fn win(g: &mut Gen) -> WindowIdentifier {
+        use WindowIdentifier::*;
+        match usize::arbitrary(g) % 10 {
+            0 => Address(crate::shared::Address::new(u64::arbitrary(g))),
+            1 => ClassRegularExpression(format!("^({})$", name(g))),
+            2 => InitialClass(name(g)),
+            3 => Title(name(g)),
+            4 => InitialTitle(name(g)),
+            5 => Tag(name(g)),
+            6 => ProcessId(u32::arbitrary(g)),
+            7 => ActiveWindow,
+            8 => Floating,
+            _ => Tiled,
+        }
+    }
+    fn work(g: &mut Gen) -> OwnedWorkspaceIdentifier {
+        use OwnedWorkspaceIdentifier::*;
+        match usize::arbitrary(g) % 15 {
+            // negative ids can't be sent, which is checked by the round trip
+            0 => Id(WorkspaceId::arbitrary(g)),
+            1 => Relative(i32::arbitrary(g)),
+            2 => RelativeMonitor(i32::arbitrary(g)),
+            3 => AbsoluteMonitor(i32::arbitrary(g)),
+            4 => RelativeMonitorIncludingEmpty(i32::arbitrary(g)),
+            5 => AbsoluteMonitorIncludingEmpty(i32::arbitrary(g)),
+            6 => RelativeOpen(i32::arbitrary(g)),
+            7 => AbsoluteOpen(i32::arbitrary(g)),
+            8 => Previous,
+            9 => PreviousPerMonitor,
+            10 => Empty,
+            11 => EmptyOnMonitor,
+            12 => EmptyNext,
+            13 => Name(name(g)),
+            _ => Special(opt(g, name)),
+        }
+    }
+    fn dir(g: &mut Gen) -> Direction {
+        pick(
+            g,
+            &[
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ],
+        )
+    }
+    fn mon(g: &mut Gen) -> OwnedMonitorIdentifier {
+        match usize::arbitrary(g) % 5 {
+            0 => OwnedMonitorIdentifier::Direction(dir(g)),
+            1 => OwnedMonitorIdentifier::Id(MonitorId::arbitrary(g)),
+            2 => OwnedMonitorIdentifier::Name(name(g)),
+            3 => OwnedMonitorIdentifier::Current,
+            _ => OwnedMonitorIdentifier::Relative(i32::arbitrary(g)),
+        }
+    }
+    fn pos(g: &mut Gen) -> Position {
+        let (x, y) = (i16::arbitrary(g), i16::arbitrary(g));
+        pick(g, &[Position::Delta(x, y), Position::Exact(x, y)])
+    }
+    fn split_ratio(g: &mut Gen) -> SplitRatio {
+        let ratio = ratio(g);
+        pick(g, &[SplitRatio::Relative(ratio), SplitRatio::Exact(ratio)])
+    }
+    fn cycle(g: &mut Gen) -> CycleDirection {
+        pick(g, &[CycleDirection::Next, CycleDirection::Previous])
+    }
+    fn lock(g: &mut Gen) -> LockType {
+        pick(g, &[LockType::Lock, LockType::Unlock, LockType::Toggle])
+    }
+    fn toggle(g: &mut Gen) -> ToggleState {
+        pick(g, &[ToggleState::On, ToggleState::Off, ToggleState::Toggle])
+    }
+    fn fullscreen_mode(g: &mut Gen) -> FullscreenMode {
+        use FullscreenMode::*;
+        pick(
+            g,
+            &[Current, None, Maximized, Fullscreen, MaximizedFullscreen],
+        )
+    }
+    fn layout_message(g: &mut Gen) -> LayoutMessage {
+        use LayoutMessage::*;
+        match usize::arbitrary(g) % 13 {
+            0 => ToggleSplit,
+            1 => SwapSplit,
+            2 => PreSelect(dir(g)),
+            3 => SwapWithMaster,
+            4 => FocusMaster,
+            5 => CycleNext,
+            6 => CyclePrev,
+            7 => AddMaster,
+            8 => RemoveMaster,
+            9 => Orientation(pick(
+                g,
+                &[
+                    MasterOrientation::Left,
+                    MasterOrientation::Right,
+                    MasterOrientation::Top,
+                    MasterOrientation::Bottom,
+                    MasterOrientation::Center,
+                ],
+            )),
+            10 => OrientationNext,
+            11 => OrientationPrev,
+            _ => MFact(split_ratio(g)),
+        }
+    }
+    fn property(g: &mut Gen) -> WindowProperty {
+        use WindowProperty::*;
+        let flag = bool::arbitrary(g);
+        match usize::arbitrary(g) % 17 {
+            0 => Alpha(ratio(g)),
+            1 => AlphaInactive(ratio(g)),
+            2 => Rounding(u16::arbitrary(g)),
+            3 => BorderSize(u16::arbitrary(g)),
+            4 => BorderColor(format!("rgb({:06x})", u32::arbitrary(g) & 0xffffff)),
+            5 => MaxSize(u16::arbitrary(g), u16::arbitrary(g)),
+            6 => MinSize(u16::arbitrary(g), u16::arbitrary(g)),
+            7 => NoMaxSize(flag),
+            8 => ForceNoBlur(flag),
+            9 => ForceOpaque(flag),
+            10 => ForceNoAnims(flag),
+            11 => ForceNoBorder(flag),
+            12 => ForceNoShadow(flag),
+            13 => ForceNoDim(flag),
+            14 => NoFocus(flag),
+            15 => KeepAspectRatio(flag),
+            _ => WindowDanceCompat(flag),
+        }
+    }
+    fn shortcut_key(g: &mut Gen) -> ShortcutKey {
+        match usize::arbitrary(g) % 3 {
+            0 => ShortcutKey::Key(name(g)),
+            1 => ShortcutKey::Code(u32::arbitrary(g)),
+            _ => ShortcutKey::Mouse(u32::arbitrary(g)),
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Dispatcher(OwnedDispatchType);
+
+    impl Arbitrary for Dispatcher {
+        fn arbitrary(g: &mut Gen) -> Self {
+            use OwnedDispatchType::*;
+            Dispatcher(match usize::arbitrary(g) % VARIANTS {
+                0 => SetCursor(name(g), u16::arbitrary(g)),
+                1 => Exec(format!("{} --class {}", name(g), name(g))),
+                2 => Pass(win(g)),
+                3 => KillActiveWindow,
+                4 => CloseWindow(win(g)),
+                5 => Workspace(work(g)),
+                6 => MoveToWorkspace(work(g), opt(g, win)),
+                7 => MoveToWorkspaceSilent(work(g), opt(g, win)),
+                8 => MoveFocusedWindowToWorkspace(work(g)),
+                9 => MoveFocusedWindowToWorkspaceSilent(work(g)),
+                10 => ToggleFloating(opt(g, win)),
+                11 => ToggleFullscreen(pick(
+                    g,
+                    &[
+                        FullscreenType::Real,
+                        FullscreenType::Maximize,
+                        FullscreenType::NoParam,
+                    ],
+                )),
+                12 => ToggleFakeFullscreen,
+                13 => ToggleDPMS(bool::arbitrary(g), opt(g, name)),
+                14 => TogglePseudo,
+                15 => TogglePin,
+                16 => MoveFocus(dir(g)),
+                17 => MoveWindow(if bool::arbitrary(g) {
+                    OwnedWindowMove::Monitor(mon(g))
+                } else {
+                    OwnedWindowMove::Direction(dir(g))
+                }),
+                18 => CenterWindow,
+                19 => ResizeActive(pos(g)),
+                20 => MoveActive(pos(g)),
+                21 => ResizeWindowPixel(pos(g), win(g)),
+                22 => MoveWindowPixel(pos(g), win(g)),
+                23 => CycleWindow(cycle(g)),
+                24 => SwapWindow(cycle(g)),
+                25 => FocusWindow(win(g)),
+                26 => FocusMonitor(mon(g)),
+                27 => ChangeSplitRatio(split_ratio(g)),
+                28 => LayoutMessage(layout_message(g)),
+                29 => ToggleOpaque,
+                30 => MoveCursorToCorner(pick(
+                    g,
+                    &[
+                        Corner::TopRight,
+                        Corner::TopLeft,
+                        Corner::BottomRight,
+                        Corner::BottomLeft,
+                    ],
+                )),
+                31 => WorkspaceOption(pick(
+                    g,
+                    &[WorkspaceOptions::AllPseudo, WorkspaceOptions::AllFloat],
+                )),
+                32 => RenameWorkspace(WorkspaceId::from(u16::arbitrary(g)), opt(g, name)),
+                33 => Exit,
+                34 => ForceRendererReload,
+                35 => MoveCurrentWorkspaceToMonitor(mon(g)),
+                36 => MoveWorkspaceToMonitor(work(g), mon(g)),
+                37 => SwapActiveWorkspaces(mon(g), mon(g)),
+                38 => BringActiveToTop,
+                39 => ToggleSpecialWorkspace,
+                40 => FocusUrgentOrLast,
+                41 => ToggleGroup,
+                42 => ChangeGroupActive(match usize::arbitrary(g) % 3 {
+                    0 => GroupActive::Forward,
+                    1 => GroupActive::Back,
+                    _ => GroupActive::Index(u8::arbitrary(g)),
+                }),
+                43 => MoveIntoGroup(dir(g)),
+                44 => MoveOutOfGroup(opt(g, win)),
+                45 => MoveWindowOrGroup(dir(g)),
+                46 => MoveGroupWindow(pick(g, &[GroupDirection::Forward, GroupDirection::Back])),
+                47 => LockGroups(lock(g)),
+                48 => LockActiveGroup(lock(g)),
+                49 => DenyWindowFromGroup(toggle(g)),
+                50 => SetIgnoreGroupLock(toggle(g)),
+                51 => Submap(match opt(g, name) {
+                    Some(name) => OwnedSubmapName::Name(name),
+                    None => OwnedSubmapName::Reset,
+                }),
+                52 => SetProp(win(g), property(g), bool::arbitrary(g)),
+                53 => SetTiled(opt(g, win)),
+                54 => SetFloating(opt(g, win)),
+                55 => PinWindow(win(g)),
+                56 => AlterZOrder(pick(g, &[ZOrder::Top, ZOrder::Bottom]), opt(g, win)),
+                57 => TagWindow(
+                    format!("{}{}", pick(g, &["", "+", "-"]), name(g)),
+                    opt(g, win),
+                ),
+                58 => FullscreenState(fullscreen_mode(g), fullscreen_mode(g)),
+                59 => FocusCurrentOrLast,
+                60 => MoveCursor(i32::arbitrary(g), i32::arbitrary(g)),
+                61 => SendShortcut(
+                    // `NONE` is left out of the wire format, so it can't come back
+                    Vec::<u8>::arbitrary(g)
+                        .into_iter()
+                        .map(|i| [Mod::SUPER, Mod::SHIFT, Mod::ALT, Mod::CTRL][usize::from(i) % 4])
+                        .collect(),
+                    shortcut_key(g),
+                    opt(g, win),
+                ),
+                62 => FocusWorkspaceOnCurrentMonitor(work(g)),
+                _ => Global(name(g), name(g)),
+            })
+        }
+    }
+
+    fn round_trip(cmd: Dispatcher) -> TestResult {
+        let cmd = cmd.0.as_borrowed();
+        let negative_id = matches!(cmd.workspace(), Some(WorkspaceIdentifier::Id(id)) if *id < 0);
+        for dispatch in [true, false] {
+            let string = match gen_dispatch_str(cmd.clone(), dispatch) {
+                Ok(v) if negative_id => {
+                    return TestResult::error(format!("{cmd:?} was generated as {v:?}"))
+                }
+                Err(_) if negative_id => continue,
+                Ok(v) => v,
+                Err(e) => return TestResult::error(format!("{cmd:?} can't be generated: {e}")),
+            };
+            let parsed = match DispatchType::parse(&string) {
+                Ok(v) => v,
+                Err(e) => return TestResult::error(format!("{string:?} can't be parsed: {e}")),
+            };
+            match gen_dispatch_str(parsed.clone(), dispatch) {
+                Ok(v) if v == string => {}
+                regenerated => {
+                    return TestResult::error(format!(
+                        "{string:?} was parsed as {parsed:?}, which gives {regenerated:?}"
+                    ))
+                }
+            }
+            // these are aliases of other dispatchers on the wire
+            let alias = matches!(
+                cmd,
+                DispatchType::MoveFocusedWindowToWorkspace(_)
+                    | DispatchType::MoveFocusedWindowToWorkspaceSilent(_)
+            );
+            if !alias && parsed != cmd {
+                return TestResult::error(format!("{string:?} was parsed as {parsed:?}"));
+            }
+        }
+        TestResult::passed()
+    }
+
+    // every variant is generated
+    let mut gen = Gen::new(100);
+    let variants: std::collections::HashSet<_> = (0..VARIANTS * 50)
+        .map(|_| std::mem::discriminant(&Dispatcher::arbitrary(&mut gen).0))
+        .collect();
+    assert_eq!(variants.len(), VARIANTS);
+
+    QuickCheck::new()
+        .tests(2000)
+        .quickcheck(round_trip as fn(Dispatcher) -> TestResult);
+}
+
+#[test]
+fn test_workspace_identifier_round_trip() {
+    for selector in [
+        "3",
+        "+2",
+        "-1",
+        "m+1",
+        "m-2",
+        "m+0",
+        "m~3",
+        "r+1",
+        "r-1",
+        "r~2",
+        "e+1",
+        "e~1",
+        "previous",
+        "previous_per_monitor",
+        "empty",
+        "emptym",
+        "emptyn",
+        "name:web",
+        "special",
+        "special:term",
+    ] {
+        let work = match WorkspaceIdentifier::parse(selector) {
+            Ok(v) => v,
+            Err(e) => panic!("Error occured: {e}"),
+        };
+        assert_eq!(work.to_string(), selector);
+        let owned = match selector.parse::<OwnedWorkspaceIdentifier>() {
+            Ok(v) => v,
+            Err(e) => panic!("Error occured: {e}"),
+        };
+        assert_eq!(owned.to_string(), selector);
+    }
+    assert_eq!(
+        WorkspaceIdentifier::parse("m+0").ok(),
+        Some(WorkspaceIdentifier::RelativeMonitor(0))
+    );
+    assert!(WorkspaceIdentifier::parse("m~x").is_err());
+    assert!(WorkspaceIdentifier::parse("").is_err());
+    // `-5` would select a relative workspace
+    assert!(gen_dispatch_str(DispatchType::Workspace(WorkspaceIdentifier::Id(-5)), true).is_err());
+}
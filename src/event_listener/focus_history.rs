@@ -0,0 +1,450 @@
+use crate::data::{Client, Clients};
+use crate::dispatch::{Dispatch, DispatchType, WindowIdentifier};
+use crate::event_listener::{
+    EventListener, EventListenerMutable, WindowMoveEvent, WindowOpenEvent,
+};
+use crate::shared::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// This enum sets which windows of the focus history are used
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FocusScope {
+    /// Every window
+    Global,
+    /// The windows on the workspace of the last focused window
+    CurrentWorkspace,
+    /// The windows on a workspace
+    Workspace(WorkspaceRef),
+}
+
+#[derive(Debug, Clone)]
+struct Cycle {
+    order: Vec<Address>,
+    index: usize,
+}
+
+#[derive(Debug, Default)]
+struct HistoryState {
+    /// Most recently focused first
+    order: Vec<Address>,
+    workspaces: HashMap<Address, WorkspaceRef>,
+    current_workspace: Option<WorkspaceRef>,
+    commit_on_release: bool,
+    cycle: Option<Cycle>,
+}
+
+impl HistoryState {
+    fn history(&self, scope: &FocusScope) -> Vec<Address> {
+        let workspace = match scope {
+            FocusScope::Global => return self.order.clone(),
+            FocusScope::CurrentWorkspace => match &self.current_workspace {
+                Some(workspace) => workspace,
+                None => return vec![],
+            },
+            FocusScope::Workspace(workspace) => workspace,
+        };
+        self.order
+            .iter()
+            .filter(|addr| self.workspaces.get(addr) == Some(workspace))
+            .copied()
+            .collect()
+    }
+
+    fn promote(&mut self, addr: Address) {
+        self.order.retain(|other| *other != addr);
+        self.order.insert(0, addr);
+    }
+
+    fn focus(&mut self, addr: Address) {
+        if let Some(workspace) = self.workspaces.get(&addr) {
+            self.current_workspace = Some(workspace.clone());
+        }
+        // while cycling, the focus changes are our own, and are only kept on commit
+        if self.cycle.is_none() {
+            self.promote(addr);
+        } else if !self.order.contains(&addr) {
+            self.order.push(addr);
+        }
+    }
+
+    fn remove(&mut self, addr: Address) {
+        self.order.retain(|other| *other != addr);
+        self.workspaces.remove(&addr);
+        if let Some(cycle) = &mut self.cycle {
+            if let Some(pos) = cycle.order.iter().position(|other| *other == addr) {
+                cycle.order.remove(pos);
+                if pos < cycle.index {
+                    cycle.index -= 1;
+                }
+                if cycle.index >= cycle.order.len() {
+                    cycle.index = 0;
+                }
+            }
+        }
+    }
+
+    fn step(&mut self, scope: &FocusScope, forward: bool) -> Option<Address> {
+        let mut cycle = match self.cycle.take() {
+            Some(cycle) => cycle,
+            None => Cycle {
+                order: self.history(scope),
+                index: 0,
+            },
+        };
+        let len = cycle.order.len();
+        if len < 2 {
+            return None;
+        }
+        cycle.index = if forward {
+            (cycle.index + 1) % len
+        } else {
+            (cycle.index + len - 1) % len
+        };
+        let target = cycle.order[cycle.index];
+        if self.commit_on_release {
+            self.cycle = Some(cycle);
+        }
+        Some(target)
+    }
+
+    fn load(&mut self, clients: impl IntoIterator<Item = Client>) {
+        let clients: Vec<Client> = clients.into_iter().collect();
+        self.workspaces = clients
+            .iter()
+            .map(|client| (client.address, client.workspace_ref()))
+            .collect();
+        if clients
+            .iter()
+            .any(|client| client.focus_history_id.is_some())
+        {
+            let mut ranked: Vec<(i32, Address)> = clients
+                .iter()
+                .filter_map(|client| Some((client.focus_history_id?, client.address)))
+                .filter(|(id, _)| *id >= 0)
+                .collect();
+            ranked.sort();
+            self.order = ranked.into_iter().map(|(_, addr)| addr).collect();
+        } else {
+            // older Hyprland versions don't report the focus history, so the order tracked
+            // from the events is kept, and the windows it hasn't seen go last
+            let workspaces = &self.workspaces;
+            self.order.retain(|addr| workspaces.contains_key(addr));
+            for client in &clients {
+                if !self.order.contains(&client.address) {
+                    self.order.push(client.address);
+                }
+            }
+        }
+        self.current_workspace = self
+            .order
+            .first()
+            .and_then(|addr| self.workspaces.get(addr))
+            .cloned();
+        self.cycle = None;
+    }
+}
+
+/// This struct keeps a most recently used list of windows, using active window events,
+/// and provides alt-tab like cycling through it
+///
+/// By default, every cycle step focuses the window right away, so it becomes the most recent
+/// one. With [FocusHistory::commit_on_release] the order is frozen while cycling,
+/// until [FocusHistory::commit] is called (eg. when the modifier is released)
+///
+/// ```rust, no_run
+/// # use hyprland::shared::HResult;
+/// # fn main() -> HResult<()> {
+/// use hyprland::event_listener::{EventListener, FocusHistory, FocusScope};
+/// let mut listener = EventListener::new();
+/// let history = FocusHistory::new().commit_on_release(true);
+/// history.load()?;
+/// history.attach(&mut listener);
+/// let switcher = history.clone();
+/// std::thread::spawn(move || {
+///     // bound to alt+tab
+///     let _ = switcher.cycle_next(FocusScope::CurrentWorkspace);
+///     // on alt release
+///     switcher.commit();
+/// });
+/// listener.start_listener()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FocusHistory {
+    state: Arc<Mutex<HistoryState>>,
+}
+
+impl FocusHistory {
+    /// This creates a new, empty, focus history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method sets whether cycling waits for [FocusHistory::commit] to reorder the history
+    pub fn commit_on_release(self, enabled: bool) -> Self {
+        lock_state(&self.state).commit_on_release = enabled;
+        self
+    }
+
+    /// This method fills the history from the `focusHistoryID` of the current clients (blocking)
+    ///
+    /// If Hyprland doesn't report it, the order tracked from the events is kept
+    pub fn load(&self) -> HResult<()> {
+        let clients = Clients::get()?;
+        lock_state(&self.state).load(clients);
+        Ok(())
+    }
+
+    /// This method fills the history from the `focusHistoryID` of the current clients (async)
+    ///
+    /// If Hyprland doesn't report it, the order tracked from the events is kept
+    pub async fn load_async(&self) -> HResult<()> {
+        let clients = Clients::get_async().await?;
+        lock_state(&self.state).load(clients);
+        Ok(())
+    }
+
+    /// This method returns the window addresses in a scope, most recently focused first
+    pub fn history(&self, scope: &FocusScope) -> Vec<Address> {
+        lock_state(&self.state).history(scope)
+    }
+
+    /// This method returns the most recently focused window in a scope
+    pub fn last(&self, scope: &FocusScope) -> Option<Address> {
+        self.history(scope).first().copied()
+    }
+
+    /// This method returns true if a cycle is waiting for [FocusHistory::commit]
+    pub fn is_cycling(&self) -> bool {
+        lock_state(&self.state).cycle.is_some()
+    }
+
+    /// This method focuses the next (less recently used) window in a scope (blocking)
+    ///
+    /// The scope is ignored if a cycle is already in progress
+    pub fn cycle_next(&self, scope: FocusScope) -> HResult<Option<Address>> {
+        let target = lock_state(&self.state).step(&scope, true);
+        focus(target)
+    }
+
+    /// This method focuses the next (less recently used) window in a scope (async)
+    ///
+    /// The scope is ignored if a cycle is already in progress
+    pub async fn cycle_next_async(&self, scope: FocusScope) -> HResult<Option<Address>> {
+        let target = lock_state(&self.state).step(&scope, true);
+        focus_async(target).await
+    }
+
+    /// This method focuses the previous (more recently used) window in a scope (blocking)
+    ///
+    /// The scope is ignored if a cycle is already in progress
+    pub fn cycle_prev(&self, scope: FocusScope) -> HResult<Option<Address>> {
+        let target = lock_state(&self.state).step(&scope, false);
+        focus(target)
+    }
+
+    /// This method focuses the previous (more recently used) window in a scope (async)
+    ///
+    /// The scope is ignored if a cycle is already in progress
+    pub async fn cycle_prev_async(&self, scope: FocusScope) -> HResult<Option<Address>> {
+        let target = lock_state(&self.state).step(&scope, false);
+        focus_async(target).await
+    }
+
+    /// This method ends the current cycle, making the selected window the most recent one
+    pub fn commit(&self) -> Option<Address> {
+        let mut state = lock_state(&self.state);
+        let cycle = state.cycle.take()?;
+        let selected = cycle.order.get(cycle.index).copied()?;
+        state.promote(selected);
+        Some(selected)
+    }
+
+    /// This method ends the current cycle, focusing back the window it started from (blocking)
+    pub fn cancel(&self) -> HResult<Option<Address>> {
+        let start = self.take_cycle_start();
+        focus(start)
+    }
+
+    /// This method ends the current cycle, focusing back the window it started from (async)
+    pub async fn cancel_async(&self) -> HResult<Option<Address>> {
+        let start = self.take_cycle_start();
+        focus_async(start).await
+    }
+
+    fn take_cycle_start(&self) -> Option<Address> {
+        lock_state(&self.state)
+            .cycle
+            .take()
+            .and_then(|cycle| cycle.order.first().copied())
+    }
+
+    fn track_open(&self, window: WindowOpenEvent) {
+        lock_state(&self.state)
+            .workspaces
+            .insert(window.0, window.1);
+    }
+
+    fn track_move(&self, window: WindowMoveEvent) {
+        lock_state(&self.state)
+            .workspaces
+            .insert(window.0, window.1);
+    }
+
+    fn track_workspace(&self, workspace: WorkspaceType) {
+        lock_state(&self.state).current_workspace = Some(workspace.into());
+    }
+
+    fn track_focus(&self, addr: Option<Address>) {
+        if let Some(addr) = addr {
+            lock_state(&self.state).focus(addr);
+        }
+    }
+
+    fn track_close(&self, addr: Address) {
+        lock_state(&self.state).remove(addr);
+    }
+
+    /// This method adds the handlers that update the history to a listener
+    pub fn attach(&self, listener: &mut EventListener) {
+        let history = self.clone();
+        listener.add_window_open_handler(move |window| history.track_open(window));
+        let history = self.clone();
+        listener.add_window_moved_handler(move |window| history.track_move(window));
+        let history = self.clone();
+        listener.add_workspace_change_handler(move |work| history.track_workspace(work));
+        let history = self.clone();
+        listener.add_active_window_address_change_handler(move |addr| history.track_focus(addr));
+        let history = self.clone();
+        listener.add_window_close_handler(move |addr| history.track_close(addr));
+    }
+
+    /// This method adds the handlers that update the history to a mutable listener
+    pub fn attach_mutable(&self, listener: &mut EventListenerMutable) {
+        let history = self.clone();
+        listener.add_window_open_handler(move |window, _| history.track_open(window));
+        let history = self.clone();
+        listener.add_window_moved_handler(move |window, _| history.track_move(window));
+        let history = self.clone();
+        listener.add_workspace_change_handler(move |work, _| history.track_workspace(work));
+        let history = self.clone();
+        listener.add_active_window_address_change_handler(move |addr, _| history.track_focus(addr));
+        let history = self.clone();
+        listener.add_window_close_handler(move |addr, _| history.track_close(addr));
+    }
+}
+
+fn focus(target: Option<Address>) -> HResult<Option<Address>> {
+    if let Some(addr) = target {
+        Dispatch::call(DispatchType::FocusWindow(WindowIdentifier::Address(addr)))?;
+    }
+    Ok(target)
+}
+
+async fn focus_async(target: Option<Address>) -> HResult<Option<Address>> {
+    if let Some(addr) = target {
+        Dispatch::call_async(DispatchType::FocusWindow(WindowIdentifier::Address(addr))).await?;
+    }
+    Ok(target)
+}
+
+#[test]
+fn test_focus_history() {
+    let client = |addr: u64, work: WorkspaceId, focus_history_id: Option<i32>| Client {
+        address: Address::from(addr),
+        workspace: crate::data::WorkspaceBasic {
+            id: work,
+            name: work.to_string(),
+        },
+        focus_history_id,
+        ..Client::default()
+    };
+    let addrs = |list: &[u64]| {
+        list.iter()
+            .map(|addr| Address::from(*addr))
+            .collect::<Vec<_>>()
+    };
+    let cycle = |history: &FocusHistory| match history.cycle_next(FocusScope::Global) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+
+    let history = FocusHistory::new();
+    lock_state(&history.state).load(vec![
+        client(3, 2, Some(2)),
+        client(1, 1, Some(0)),
+        client(2, 1, Some(1)),
+        client(4, 1, Some(-1)),
+    ]);
+    assert_eq!(history.history(&FocusScope::Global), addrs(&[1, 2, 3]));
+    assert_eq!(
+        history.history(&FocusScope::CurrentWorkspace),
+        addrs(&[1, 2])
+    );
+    assert_eq!(
+        history.history(&FocusScope::Workspace(WorkspaceRef::Id(2))),
+        addrs(&[3])
+    );
+
+    history.track_focus(Some(Address::from(3)));
+    assert_eq!(history.history(&FocusScope::Global), addrs(&[3, 1, 2]));
+    assert_eq!(
+        history.last(&FocusScope::CurrentWorkspace),
+        Some(Address::from(3))
+    );
+    history.track_close(Address::from(1));
+    assert_eq!(history.history(&FocusScope::Global), addrs(&[3, 2]));
+
+    let recorder = Recorder::start();
+    let history = history.commit_on_release(true);
+    history.track_focus(Some(Address::from(1)));
+    assert_eq!(cycle(&history), Some(Address::from(3)));
+    history.track_focus(Some(Address::from(3)));
+    assert_eq!(cycle(&history), Some(Address::from(2)));
+    history.track_focus(Some(Address::from(2)));
+    assert!(history.is_cycling());
+    assert_eq!(history.history(&FocusScope::Global), addrs(&[1, 3, 2]));
+    assert_eq!(history.commit(), Some(Address::from(2)));
+    assert_eq!(history.history(&FocusScope::Global), addrs(&[2, 1, 3]));
+
+    // without focusHistoryID, the tracked order is kept
+    lock_state(&history.state).load(vec![
+        client(1, 1, None),
+        client(4, 2, None),
+        client(2, 1, None),
+    ]);
+    assert_eq!(history.history(&FocusScope::Global), addrs(&[2, 1, 4]));
+    assert_eq!(
+        history.last(&FocusScope::CurrentWorkspace),
+        Some(Address::from(2))
+    );
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "dispatch focuswindow address:0x3",
+            "dispatch focuswindow address:0x2"
+        ]
+    );
+}
+
+#[test]
+fn test_focus_history_without_ids() {
+    // older Hyprland versions don't send the focus history
+    let mut client = match serde_json::to_value(Client {
+        focus_history_id: Some(0),
+        ..Client::default()
+    }) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    if let serde_json::Value::Object(fields) = &mut client {
+        fields.remove("focusHistoryID");
+    }
+    let clients: Vec<Client> = match crate::data::parse_data("clients", format!("[{client}]")) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(clients[0].focus_history_id, None);
+}
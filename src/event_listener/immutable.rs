@@ -46,9 +46,10 @@ impl EventListener {
     add_listener!(workspace_moved, MonitorEventData, "a workspace is moved", "workspace was moved" => id);
     add_listener!(active_monitor_change d, MonitorEventData, "the active monitor is changed", "Active monitor changed to" => data);
     add_listener!(active_window_change d, Option<WindowEventData>, "the active window is changed", "Active window changed" => data);
+    add_listener!(active_window_address_change d, Option<Address>, "the active window is changed, with its address (needs `activewindowv2`, Hyprland >= v0.20.0)", "Active window address changed" => data);
     add_listener!(fullscreen_state_change d, bool, "the active monitor is changed", "Fullscreen is on" => state);
-    add_listener!(monitor_added, String, "a new monitor is added", "Monitor added" => data);
-    add_listener!(monitor_removed, String, "a monitor is removed", "Monitor removed" => data);
+    add_listener!(monitor_added, MonitorRef, "a new monitor is added", "Monitor added" => data);
+    add_listener!(monitor_removed, MonitorRef, "a monitor is removed", "Monitor removed" => data);
     add_listener!(window_open, WindowOpenEvent, "a window is opened", "Window opened" => data);
     add_listener!(window_close, Address, "a window is closed", "Window closed" => data);
     add_listener!(window_moved, WindowMoveEvent, "a window is moved", "Window moved" => data);
@@ -58,6 +59,7 @@ impl EventListener {
     add_listener!(layer_closed, String, "a layer is closed", "Layer closed" => data);
     add_listener!(float_state, WindowFloatEventData, "the float state of a window is changed", "Float state changed" => data);
     add_listener!(urgent_state, Address, "the urgent state of a window is changed", "urgent state changed" => data);
+    add_listener!(minimize, MinimizeEventData, "a window requests to be minimized or unminimized", "Minimize requested" => data);
 
     fn event_executor(&self, event: &Event) {
         match event {
@@ -74,6 +76,9 @@ impl EventListener {
                 self
             ),
             Event::ActiveWindowChanged(None) => arm!(None, active_window_changed_events, self),
+            Event::ActiveWindowAddressChanged(addr) => {
+                arm!(*addr, active_window_address_changed_events, self)
+            }
             Event::FullscreenStateChanged(bool) => {
                 arm!(*bool, fullscreen_state_changed_events, self)
             }
@@ -81,7 +86,7 @@ impl EventListener {
             Event::MonitorRemoved(monitor) => {
                 arm!(monitor.clone(), monitor_removed_events, self)
             }
-            Event::WindowClosed(addr) => arm!(addr.clone(), window_close_events, self),
+            Event::WindowClosed(addr) => arm!(*addr, window_close_events, self),
             Event::WindowMoved(even) => arm!(even.clone(), window_moved_events, self),
             Event::WindowOpened(even) => arm!(even.clone(), window_open_events, self),
             Event::LayoutChanged(even) => {
@@ -93,7 +98,8 @@ impl EventListener {
                 arm!(namespace.clone(), layer_closed_events, self)
             }
             Event::FloatStateChanged(even) => arm!(even.clone(), float_state_events, self),
-            Event::UrgentStateChanged(even) => arm!(even.clone(), urgent_state_events, self),
+            Event::UrgentStateChanged(even) => arm!(*even, urgent_state_events, self),
+            Event::Minimize(even) => arm!(even.clone(), minimize_events, self),
         }
     }
 
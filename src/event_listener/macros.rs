@@ -126,6 +126,7 @@ macro_rules! init_events {
             workspace_moved_events: vec![],
             active_monitor_changed_events: vec![],
             active_window_changed_events: vec![],
+            active_window_address_changed_events: vec![],
             fullscreen_state_changed_events: vec![],
             monitor_removed_events: vec![],
             monitor_added_events: vec![],
@@ -138,6 +139,7 @@ macro_rules! init_events {
             layer_closed_events: vec![],
             float_state_events: vec![],
             urgent_state_events: vec![],
+            minimize_events: vec![],
         }
     };
 }
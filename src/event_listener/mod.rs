@@ -9,3 +9,9 @@ pub use crate::event_listener::immutable::EventListener;
 
 mod immutable;
 pub use crate::event_listener::mutable::EventListener as EventListenerMutable;
+
+mod submap;
+pub use crate::event_listener::submap::SubmapTracker;
+
+mod focus_history;
+pub use crate::event_listener::focus_history::{FocusHistory, FocusScope};
@@ -50,7 +50,7 @@ impl EventListener {
                 },
                 active_monitor: match Monitors::get() {
                     Ok(mut monitors) => match monitors.find(|item| item.focused) {
-                        Some(mon) => mon.name,
+                        Some(mon) => MonitorRef::from(&mon),
                         None => panic!("No active monitor?"),
                     },
                     Err(e) => panic!("A error occured when parsing json with serde {e}"),
@@ -69,9 +69,10 @@ impl EventListener {
     mut_add_listener!(workspace_moved, MonitorEventData, "a workspace is moved", "workspace was moved" => id);
     mut_add_listener!(active_monitor_change d, MonitorEventData, "the active monitor is changed", "Active monitor changed to" => data);
     mut_add_listener!(active_window_change d, Option<WindowEventData>, "the active window is changed", "Active window changed" => data);
+    mut_add_listener!(active_window_address_change d, Option<Address>, "the active window is changed, with its address (needs `activewindowv2`, Hyprland >= v0.20.0)", "Active window address changed" => data);
     mut_add_listener!(fullscreen_state_change d, bool, "the active monitor is changed", "Fullscreen is on" => state);
-    mut_add_listener!(monitor_added, String, "a new monitor is added", "Monitor added" => data);
-    mut_add_listener!(monitor_removed, String, "a monitor is removed", "Monitor removed" => data);
+    mut_add_listener!(monitor_added, MonitorRef, "a new monitor is added", "Monitor added" => data);
+    mut_add_listener!(monitor_removed, MonitorRef, "a monitor is removed", "Monitor removed" => data);
     mut_add_listener!(window_open, WindowOpenEvent, "a window is opened", "Window opened" => data);
     mut_add_listener!(window_close, Address, "a window is closed", "Window closed" => data);
     mut_add_listener!(window_moved, WindowMoveEvent, "a window is moved", "Window moved" => data);
@@ -81,6 +82,7 @@ impl EventListener {
     mut_add_listener!(layer_closed, String, "a layer is closed", "Layer closed" => data);
     mut_add_listener!(float_state, WindowFloatEventData, "the float state of a window is changed", "Float state changed" => data);
     mut_add_listener!(urgent_state, Address, "the urgent state of a window is changed", "urgent state changed" => data);
+    mut_add_listener!(minimize, MinimizeEventData, "a window requests to be minimized or unminimized", "Minimize requested" => data);
 
     async fn event_executor(&mut self, event: &Event) -> HResult<()> {
         match event {
@@ -105,6 +107,9 @@ impl EventListener {
                 mut_arm!(Some(even.clone()), active_window_changed_events, self)
             }
             Event::ActiveWindowChanged(None) => mut_arm!(None, active_window_changed_events, self),
+            Event::ActiveWindowAddressChanged(addr) => {
+                mut_arm!(*addr, active_window_address_changed_events, self)
+            }
             Event::FullscreenStateChanged(bool) => mut_state_arm!(
                 *bool,
                 fullscreen_state_changed_events,
@@ -116,7 +121,7 @@ impl EventListener {
             Event::MonitorRemoved(monitor) => {
                 mut_arm!(monitor.clone(), monitor_removed_events, self)
             }
-            Event::WindowClosed(addr) => mut_arm!(addr.clone(), window_close_events, self),
+            Event::WindowClosed(addr) => mut_arm!(*addr, window_close_events, self),
             Event::WindowMoved(even) => mut_arm!(even.clone(), window_moved_events, self),
             Event::WindowOpened(even) => mut_arm!(even.clone(), window_open_events, self),
             Event::LayoutChanged(lay) => mut_arm!(lay.clone(), keyboard_layout_change_events, self),
@@ -124,7 +129,8 @@ impl EventListener {
             Event::LayerOpened(even) => mut_arm!(even.clone(), layer_open_events, self),
             Event::LayerClosed(even) => mut_arm!(even.clone(), layer_closed_events, self),
             Event::FloatStateChanged(even) => mut_arm!(even.clone(), float_state_events, self),
-            Event::UrgentStateChanged(even) => mut_arm!(even.clone(), urgent_state_events, self),
+            Event::UrgentStateChanged(even) => mut_arm!(*even, urgent_state_events, self),
+            Event::Minimize(even) => mut_arm!(even.clone(), minimize_events, self),
         }
         Ok(())
     }
@@ -162,6 +168,9 @@ impl EventListener {
             Event::ActiveWindowChanged(None) => {
                 mut_arm_sync!(None, active_window_changed_events, self)
             }
+            Event::ActiveWindowAddressChanged(addr) => {
+                mut_arm_sync!(*addr, active_window_address_changed_events, self)
+            }
             Event::FullscreenStateChanged(bool) => mut_state_arm_sync!(
                 *bool,
                 fullscreen_state_changed_events,
@@ -175,7 +184,7 @@ impl EventListener {
             Event::MonitorRemoved(monitor) => {
                 mut_arm_sync!(monitor.clone(), monitor_removed_events, self)
             }
-            Event::WindowClosed(addr) => mut_arm_sync!(addr.clone(), window_close_events, self),
+            Event::WindowClosed(addr) => mut_arm_sync!(*addr, window_close_events, self),
             Event::WindowMoved(even) => mut_arm_sync!(even.clone(), window_moved_events, self),
             Event::WindowOpened(even) => mut_arm_sync!(even.clone(), window_open_events, self),
             Event::LayoutChanged(lay) => {
@@ -188,8 +197,9 @@ impl EventListener {
                 mut_arm_sync!(even.clone(), float_state_events, self)
             }
             Event::UrgentStateChanged(even) => {
-                mut_arm_sync!(even.clone(), urgent_state_events, self)
+                mut_arm_sync!(*even, urgent_state_events, self)
             }
+            Event::Minimize(even) => mut_arm_sync!(even.clone(), minimize_events, self),
         }
         Ok(())
     }
@@ -1,6 +1,5 @@
 use crate::shared::*;
 use regex::{Error as RegexError, Regex, RegexSet};
-use std::io;
 
 /// This trait provides shared behaviour for listener types
 #[async_trait]
@@ -28,9 +27,10 @@ pub(crate) struct Events {
     pub(crate) workspace_moved_events: Closures<MonitorEventData>,
     pub(crate) active_monitor_changed_events: Closures<MonitorEventData>,
     pub(crate) active_window_changed_events: Closures<Option<WindowEventData>>,
+    pub(crate) active_window_address_changed_events: Closures<Option<Address>>,
     pub(crate) fullscreen_state_changed_events: Closures<bool>,
-    pub(crate) monitor_removed_events: Closures<String>,
-    pub(crate) monitor_added_events: Closures<String>,
+    pub(crate) monitor_removed_events: Closures<MonitorRef>,
+    pub(crate) monitor_added_events: Closures<MonitorRef>,
     pub(crate) keyboard_layout_change_events: Closures<LayoutEvent>,
     pub(crate) sub_map_changed_events: Closures<String>,
     pub(crate) window_open_events: Closures<WindowOpenEvent>,
@@ -40,6 +40,7 @@ pub(crate) struct Events {
     pub(crate) layer_closed_events: Closures<String>,
     pub(crate) float_state_events: Closures<WindowFloatEventData>,
     pub(crate) urgent_state_events: Closures<Address>,
+    pub(crate) minimize_events: Closures<MinimizeEventData>,
 }
 
 /// The data for the event executed when moving a window to a new workspace
@@ -47,8 +48,8 @@ pub(crate) struct Events {
 pub struct WindowMoveEvent(
     /// Window address
     pub Address,
-    /// The workspace name
-    pub String,
+    /// The workspace
+    pub WorkspaceRef,
 );
 
 /// The data for the event executed when opening a new window
@@ -56,8 +57,8 @@ pub struct WindowMoveEvent(
 pub struct WindowOpenEvent(
     /// Window address
     pub Address,
-    /// The workspace name
-    pub String,
+    /// The workspace
+    pub WorkspaceRef,
     /// Window class
     pub String,
     /// Window title
@@ -79,7 +80,7 @@ pub struct State {
     /// The active workspace
     pub active_workspace: WorkspaceType,
     /// The active monitor
-    pub active_monitor: String,
+    pub active_monitor: MonitorRef,
     /// The fullscreen state
     pub fullscreen_state: bool,
 }
@@ -96,21 +97,19 @@ impl State {
                     .await?;
             }
             if old.active_workspace != state.active_workspace {
-                use crate::dispatch::WorkspaceIdentifierWithSpecial;
+                use crate::dispatch::WorkspaceIdentifier;
                 Dispatch::call_async(DispatchType::Workspace(match &state.active_workspace {
-                    WorkspaceType::Regular(name) => WorkspaceIdentifierWithSpecial::Name(name),
-                    WorkspaceType::Special(opt) => {
-                        WorkspaceIdentifierWithSpecial::Special(match opt {
-                            Some(name) => Some(name),
-                            None => None,
-                        })
-                    }
+                    WorkspaceType::Regular(name) => WorkspaceIdentifier::Name(name),
+                    WorkspaceType::Special(opt) => WorkspaceIdentifier::Special(match opt {
+                        Some(name) => Some(name),
+                        None => None,
+                    }),
                 }))
                 .await?;
             }
             if old.active_monitor != state.active_monitor {
                 use crate::dispatch::MonitorIdentifier;
-                Dispatch::call_async(DispatchType::FocusMonitor(MonitorIdentifier::Name(
+                Dispatch::call_async(DispatchType::FocusMonitor(MonitorIdentifier::from(
                     &state.active_monitor,
                 )))
                 .await?;
@@ -128,20 +127,18 @@ impl State {
                 Dispatch::call(DispatchType::ToggleFullscreen(FullscreenType::NoParam))?;
             }
             if old.active_workspace != state.active_workspace {
-                use crate::dispatch::WorkspaceIdentifierWithSpecial;
+                use crate::dispatch::WorkspaceIdentifier;
                 Dispatch::call(DispatchType::Workspace(match &state.active_workspace {
-                    WorkspaceType::Regular(name) => WorkspaceIdentifierWithSpecial::Name(name),
-                    WorkspaceType::Special(opt) => {
-                        WorkspaceIdentifierWithSpecial::Special(match opt {
-                            Some(name) => Some(name),
-                            None => None,
-                        })
-                    }
+                    WorkspaceType::Regular(name) => WorkspaceIdentifier::Name(name),
+                    WorkspaceType::Special(opt) => WorkspaceIdentifier::Special(match opt {
+                        Some(name) => Some(name),
+                        None => None,
+                    }),
                 }))?;
             }
             if old.active_monitor != state.active_monitor {
                 use crate::dispatch::MonitorIdentifier;
-                Dispatch::call(DispatchType::FocusMonitor(MonitorIdentifier::Name(
+                Dispatch::call(DispatchType::FocusMonitor(MonitorIdentifier::from(
                     &state.active_monitor,
                 )))?;
             };
@@ -194,8 +191,8 @@ pub struct WindowEventData(
 /// This tuple struct holds monitor event data
 #[derive(Debug, Clone)]
 pub struct MonitorEventData(
-    /// The monitor name
-    pub String,
+    /// The monitor
+    pub MonitorRef,
     /// The workspace
     pub WorkspaceType,
 );
@@ -209,6 +206,15 @@ pub struct WindowFloatEventData(
     pub bool,
 );
 
+/// This tuple struct holds minimize request data
+#[derive(Debug, Clone)]
+pub struct MinimizeEventData(
+    /// The window address
+    pub Address,
+    /// Whether the window should be minimized (or unminimized)
+    pub bool,
+);
+
 /// This enum holds every event type
 #[derive(Debug, Clone)]
 pub(crate) enum Event {
@@ -217,10 +223,11 @@ pub(crate) enum Event {
     WorkspaceAdded(WorkspaceType),
     WorkspaceMoved(MonitorEventData),
     ActiveWindowChanged(Option<WindowEventData>),
+    ActiveWindowAddressChanged(Option<Address>),
     ActiveMonitorChanged(MonitorEventData),
     FullscreenStateChanged(bool),
-    MonitorAdded(String),
-    MonitorRemoved(String),
+    MonitorAdded(MonitorRef),
+    MonitorRemoved(MonitorRef),
     WindowOpened(WindowOpenEvent),
     WindowClosed(Address),
     WindowMoved(WindowMoveEvent),
@@ -230,6 +237,7 @@ pub(crate) enum Event {
     LayerClosed(String),
     FloatStateChanged(WindowFloatEventData),
     UrgentStateChanged(Address),
+    Minimize(MinimizeEventData),
 }
 
 fn check_for_regex_error(val: Result<Regex, RegexError>) -> Regex {
@@ -295,14 +303,17 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
             r"fullscreen>>(?P<state>0|1)",
             r"monitorremoved>>(?P<monitor>.*)",
             r"monitoradded>>(?P<monitor>.*)",
-            r"openwindow>>(?P<address>.*),(?P<workspace>.*),(?P<class>.*),(?P<title>.*)",
+            r"openwindow>>(?P<address>[^,]*),(?P<workspace>[^,]*),(?P<class>[^,]*),(?P<title>.*)",
             r"closewindow>>(?P<address>.*)",
-            r"movewindow>>(?P<address>.*),(?P<workspace>.*)",
+            r"movewindow>>(?P<address>[^,]*),(?P<workspace>.*)",
             r"activelayout>>(?P<keyboard>.*)(?P<layout>.*)",
             r"submap>>(?P<submap>.*)",
             r"openlayer>>(?P<namespace>.*)",
             r"closelayer>>(?P<namespace>.*)",
-            r"changefloatingmode>>(?P<address>.*),(?P<floatstate>[0-1])",
+            r"changefloatingmode>>(?P<address>[^,]*),(?P<floatstate>[0-1])",
+            r"activewindowv2>>(?P<address>.*)",
+            r"urgent>>(?P<address>.*)",
+            r"minimize>>(?P<address>[^,]*),(?P<state>0|1)",
             r"(?P<Event>.*)>>.*?"
         ]));
         static ref EVENT_REGEXES: Vec<Regex> = EVENT_SET
@@ -313,6 +324,22 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
         static ref EVENT_LEN: usize = EVENT_SET.len() - 1;
     }
 
+    // a malformed event is skipped, so it doesn't stop the listener
+    macro_rules! parse_or_skip {
+        ($parsed:expr, $item:expr) => {
+            match $parsed {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    eprintln!(
+                        "Hyprland-rs skipped an event it couldn't parse ({e}): {}",
+                        $item
+                    );
+                    continue;
+                }
+            }
+        };
+    }
+
     let event_iter = event.trim().split('\n');
 
     let mut events: Vec<Event> = vec![];
@@ -357,7 +384,7 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
                     let workspace = parse_string_as_work(captures["workspace"].to_string());
                     let monitor = &captures["monitor"];
                     events.push(Event::WorkspaceMoved(MonitorEventData(
-                        monitor.to_string(),
+                        MonitorRef::from(monitor),
                         workspace,
                     )));
                 }
@@ -366,7 +393,7 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
                     let monitor = &captures["monitor"];
                     let workspace = &captures["workspace"];
                     events.push(Event::ActiveMonitorChanged(MonitorEventData(
-                        monitor.to_string(),
+                        MonitorRef::from(monitor),
                         WorkspaceType::Regular(workspace.to_string()),
                     )));
                 }
@@ -391,12 +418,12 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
                 7 => {
                     // MonitorRemoved
                     let monitor = &captures["monitor"];
-                    events.push(Event::MonitorRemoved(monitor.to_string()));
+                    events.push(Event::MonitorRemoved(MonitorRef::from(monitor)));
                 }
                 8 => {
                     // MonitorAdded
                     let monitor = &captures["monitor"];
-                    events.push(Event::MonitorAdded(monitor.to_string()));
+                    events.push(Event::MonitorAdded(MonitorRef::from(monitor)));
                 }
                 9 => {
                     // WindowOpened
@@ -405,8 +432,8 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
                     let class = &captures["class"];
                     let title = &captures["title"];
                     events.push(Event::WindowOpened(WindowOpenEvent(
-                        Address::new(addr),
-                        workspace.to_string(),
+                        parse_or_skip!(Address::parse(addr), item),
+                        parse_or_skip!(workspace.parse::<WorkspaceRef>(), item),
                         class.to_string(),
                         title.to_string(),
                     )));
@@ -414,15 +441,18 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
                 10 => {
                     // WindowClosed
                     let addr = &captures["address"];
-                    events.push(Event::WindowClosed(Address::new(addr)));
+                    events.push(Event::WindowClosed(parse_or_skip!(
+                        Address::parse(addr),
+                        item
+                    )));
                 }
                 11 => {
                     // WindowMoved
                     let addr = &captures["address"];
                     let work = &captures["workspace"];
                     events.push(Event::WindowMoved(WindowMoveEvent(
-                        Address::new(addr),
-                        work.to_string(),
+                        parse_or_skip!(Address::parse(addr), item),
+                        parse_or_skip!(work.parse::<WorkspaceRef>(), item),
                     )));
                 }
                 12 => {
@@ -454,14 +484,38 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
                     let addr = &captures["address"];
                     let state = &captures["floatstate"] == "0";
                     events.push(Event::FloatStateChanged(WindowFloatEventData(
-                        Address::new(addr),
+                        parse_or_skip!(Address::parse(addr), item),
                         state,
                     )));
                 }
+                17 => {
+                    // ActiveWindowAddressChanged
+                    let addr = &captures["address"];
+                    if addr.is_empty() || addr == "," {
+                        events.push(Event::ActiveWindowAddressChanged(None));
+                    } else {
+                        events.push(Event::ActiveWindowAddressChanged(Some(parse_or_skip!(
+                            Address::parse(addr),
+                            item
+                        ))));
+                    }
+                }
                 18 => {
                     // UrgentStateChanged
                     let addr = &captures["address"];
-                    events.push(Event::UrgentStateChanged(Address::new(addr)));
+                    events.push(Event::UrgentStateChanged(parse_or_skip!(
+                        Address::parse(addr),
+                        item
+                    )));
+                }
+                19 => {
+                    // Minimize
+                    let addr = &captures["address"];
+                    let state = &captures["state"] == "1";
+                    events.push(Event::Minimize(MinimizeEventData(
+                        parse_or_skip!(Address::parse(addr), item),
+                        state,
+                    )));
                 }
                 _ => unreachable!(), //panic!("There are only 16 items in the array? prob a regex issue 🤷"),
             }
@@ -483,12 +537,63 @@ pub(crate) fn event_parser(event: String) -> HResult<Vec<Event>> {
                 };
             }
         } else {
-            return Err(HyprError::IoError(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Event matched more or less than one regex (not a unknown event issue!)",
-            )));
+            eprintln!("Hyprland-rs skipped an event that matched more than one regex: {item}");
         }
     }
 
     Ok(events)
 }
+
+#[test]
+fn test_active_window_v2_parsing() {
+    let events = match event_parser("activewindowv2>>55d6ab6a4b50\nactivewindowv2>>,".to_string()) {
+        Ok(events) => events,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert!(matches!(
+        events[0],
+        Event::ActiveWindowAddressChanged(Some(addr)) if addr == Address::new(0x55d6ab6a4b50)
+    ));
+    assert!(matches!(events[1], Event::ActiveWindowAddressChanged(None)));
+}
+
+#[test]
+fn test_minimize_parsing() {
+    let events =
+        match event_parser("minimize>>55d6ab6a4b50,1\nminimize>>55d6ab6a4b50,0".to_string()) {
+            Ok(events) => events,
+            Err(e) => panic!("Error occured: {e}"),
+        };
+    assert!(matches!(
+        events[0],
+        Event::Minimize(MinimizeEventData(addr, true)) if addr == Address::new(0x55d6ab6a4b50)
+    ));
+    assert!(matches!(
+        events[1],
+        Event::Minimize(MinimizeEventData(_, false))
+    ));
+}
+
+#[test]
+fn test_comma_in_title_parsing() {
+    let events = match event_parser(
+        "openwindow>>55d6ab6a4b50,1,firefox,Foo, Bar\nmovewindow>>55d6ab6a4b50,name,with,commas\nclosewindow>>not an address\nclosewindow>>55d6ab6a4b50".to_string(),
+    ) {
+        Ok(events) => events,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let addr = Address::new(0x55d6ab6a4b50);
+    assert_eq!(events.len(), 3);
+    assert!(matches!(
+        &events[0],
+        Event::WindowOpened(WindowOpenEvent(a, work, class, title))
+            if *a == addr && *work == WorkspaceRef::Id(1) && class == "firefox" && title == "Foo, Bar"
+    ));
+    assert!(matches!(
+        &events[1],
+        Event::WindowMoved(WindowMoveEvent(a, work))
+            if *a == addr && *work == WorkspaceRef::Name("name,with,commas".to_string())
+    ));
+    // the malformed event is skipped
+    assert!(matches!(events[2], Event::WindowClosed(a) if a == addr));
+}
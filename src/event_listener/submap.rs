@@ -0,0 +1,62 @@
+use crate::event_listener::{EventListener, EventListenerMutable};
+use crate::shared::lock_state;
+use std::sync::{Arc, Mutex};
+
+/// This struct keeps track of the current submap, using submap change events
+///
+/// ```rust, no_run
+/// use hyprland::event_listener::{EventListener, SubmapTracker};
+/// let mut listener = EventListener::new();
+/// let tracker = SubmapTracker::new();
+/// tracker.attach(&mut listener);
+/// let reader = tracker.clone();
+/// listener.add_window_open_handler(move |_| println!("current submap: {:?}", reader.current()));
+/// listener.start_listener();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SubmapTracker {
+    current: Arc<Mutex<Option<String>>>,
+}
+
+impl SubmapTracker {
+    /// This creates a new tracker, starting in the default submap
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method returns the current submap, [None] being the default one
+    pub fn current(&self) -> Option<String> {
+        lock_state(&self.current).clone()
+    }
+
+    fn update(&self, submap: String) {
+        let submap = if submap.is_empty() || submap == "reset" {
+            None
+        } else {
+            Some(submap)
+        };
+        *lock_state(&self.current) = submap;
+    }
+
+    /// This method adds the handler that updates the tracker to a listener
+    pub fn attach(&self, listener: &mut EventListener) {
+        let tracker = self.clone();
+        listener.add_sub_map_change_handler(move |submap| tracker.update(submap));
+    }
+
+    /// This method adds the handler that updates the tracker to a mutable listener
+    pub fn attach_mutable(&self, listener: &mut EventListenerMutable) {
+        let tracker = self.clone();
+        listener.add_sub_map_change_handler(move |submap, _| tracker.update(submap));
+    }
+}
+
+#[test]
+fn test_submap_tracker() {
+    let tracker = SubmapTracker::new();
+    assert_eq!(tracker.current(), None);
+    tracker.update("resize".to_string());
+    assert_eq!(tracker.clone().current(), Some("resize".to_string()));
+    tracker.update("".to_string());
+    assert_eq!(tracker.current(), None);
+}
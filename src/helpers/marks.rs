@@ -0,0 +1,421 @@
+//! i3 style marks: labels attached to windows, to jump to them or swap them
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::{HResult, HyprDataActiveOptional};
+//! # fn main() -> HResult<()> {
+//! use hyprland::data::Client;
+//! use hyprland::event_listener::EventListener;
+//! use hyprland::helpers::marks::Marks;
+//! let marks = Marks::new();
+//! marks.load()?;
+//! let mut listener = EventListener::new();
+//! marks.attach(&mut listener);
+//! if let Some(client) = Client::get_active()? {
+//!     marks.mark("editor", client.address)?;
+//! }
+//! marks.jump("editor")?;
+//! listener.start_listener()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use crate::data::Clients;
+use crate::dispatch::{OwnedWorkspaceIdentifier, WorkspaceIdentifier};
+use crate::event_listener::{EventListener, EventListenerMutable};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// The prefix of the Hyprland tags used for marks
+pub const MARK_TAG_PREFIX: &str = "mark_";
+
+#[derive(Debug, Default)]
+struct MarksState {
+    marks: BTreeMap<String, Address>,
+    tags: bool,
+}
+
+impl MarksState {
+    fn tag(&self, sign: char, label: &str, addr: Address) -> Option<OwnedDispatchType> {
+        self.tags.then(|| {
+            OwnedDispatchType::TagWindow(
+                format!("{sign}{MARK_TAG_PREFIX}{label}"),
+                Some(WindowIdentifier::Address(addr)),
+            )
+        })
+    }
+
+    fn mark_dispatches(&self, label: &str, addr: Address) -> Vec<OwnedDispatchType> {
+        let mut dispatches = vec![];
+        // like in i3, a mark is on a single window
+        if let Some(old) = self.marks.get(label) {
+            dispatches.extend(self.tag('-', label, *old));
+        }
+        dispatches.extend(self.tag('+', label, addr));
+        dispatches
+    }
+
+    fn unmark_dispatches(&self, label: &str) -> (Option<Address>, Vec<OwnedDispatchType>) {
+        match self.marks.get(label) {
+            Some(addr) => (
+                Some(*addr),
+                self.tag('-', label, *addr).into_iter().collect(),
+            ),
+            None => (None, vec![]),
+        }
+    }
+
+    fn load(&mut self, clients: impl IntoIterator<Item = Client>) {
+        self.marks.clear();
+        for client in clients {
+            for tag in client.tags.iter().flatten() {
+                // dynamic tags (set by window rules) end with a `*`
+                let tag = tag.trim_end_matches('*');
+                if let Some(label) = tag.strip_prefix(MARK_TAG_PREFIX) {
+                    self.marks.insert(label.to_string(), client.address);
+                }
+            }
+        }
+    }
+}
+
+fn swap_dispatches(first: &Client, second: &Client) -> HResult<Vec<OwnedDispatchType>> {
+    let same_workspace = first.workspace.id == second.workspace.id;
+    if same_workspace && !first.floating && !second.floating {
+        // `swapwindow` only takes a direction, so the tiled windows would stay where they are
+        return Err(HyprError::IoError(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Hyprland can't swap two tiled windows on the same workspace",
+        )));
+    }
+    let mut dispatches = vec![];
+    if !same_workspace {
+        for (win, other) in [(first, second), (second, first)] {
+            dispatches.push(OwnedDispatchType::MoveToWorkspaceSilent(
+                OwnedWorkspaceIdentifier::from(WorkspaceIdentifier::from(&other.workspace_ref())),
+                Some(WindowIdentifier::Address(win.address)),
+            ));
+        }
+    }
+    for (win, other) in [(first, second), (second, first)] {
+        let id = Some(WindowIdentifier::Address(win.address));
+        if other.floating {
+            if !win.floating {
+                dispatches.push(OwnedDispatchType::SetFloating(id));
+            }
+            dispatches.extend(place_window(win.address, other.geometry()));
+        } else if win.floating {
+            dispatches.push(OwnedDispatchType::SetTiled(id));
+        }
+    }
+    Ok(dispatches)
+}
+
+/// This struct attaches labels (marks) to windows
+///
+/// Marks are kept in a local map, and also set as Hyprland tags (`mark_{label}`) when
+/// [Capabilities::window_tags] is true, so they can be used in window rules and are kept
+/// across restarts of the program (see [Marks::load]).
+/// The handlers added with [Marks::attach] remove the marks of closed windows.
+#[derive(Debug, Clone)]
+pub struct Marks {
+    state: Arc<Mutex<MarksState>>,
+}
+
+impl Default for Marks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Marks {
+    /// Creates an empty set of marks, using tags if the current Hyprland supports them
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MarksState {
+                marks: BTreeMap::new(),
+                tags: Capabilities::current().window_tags,
+            })),
+        }
+    }
+
+    /// Sets whether marks are also set as Hyprland tags
+    pub fn use_tags(self, tags: bool) -> Self {
+        lock_state(&self.state).tags = tags;
+        self
+    }
+
+    fn unknown(label: &str) -> HyprError {
+        HyprError::IoError(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("There is no window marked {label:?}"),
+        ))
+    }
+
+    /// This method reads the marks back from the window tags, if tags are used (blocking)
+    pub fn load(&self) -> HResult<()> {
+        if lock_state(&self.state).tags {
+            let clients = Clients::get()?;
+            lock_state(&self.state).load(clients);
+        }
+        Ok(())
+    }
+
+    /// This method reads the marks back from the window tags, if tags are used (async)
+    pub async fn load_async(&self) -> HResult<()> {
+        if lock_state(&self.state).tags {
+            let clients = Clients::get_async().await?;
+            lock_state(&self.state).load(clients);
+        }
+        Ok(())
+    }
+
+    /// This method returns every mark, and the window it is on
+    pub fn marks(&self) -> Vec<(String, Address)> {
+        lock_state(&self.state)
+            .marks
+            .iter()
+            .map(|(label, addr)| (label.clone(), *addr))
+            .collect()
+    }
+
+    /// This method returns the window with a mark
+    pub fn get(&self, label: &str) -> Option<Address> {
+        lock_state(&self.state).marks.get(label).copied()
+    }
+
+    /// This method returns the marks of a window
+    pub fn labels(&self, addr: Address) -> Vec<String> {
+        lock_state(&self.state)
+            .marks
+            .iter()
+            .filter(|(_, marked)| **marked == addr)
+            .map(|(label, _)| label.clone())
+            .collect()
+    }
+
+    /// This method marks a window, moving the mark if another window had it (blocking)
+    ///
+    /// The mark is only moved once the tags are set
+    pub fn mark(&self, label: &str, addr: Address) -> HResult<()> {
+        let dispatches = lock_state(&self.state).mark_dispatches(label, addr);
+        call_all(&dispatches)?;
+        lock_state(&self.state)
+            .marks
+            .insert(label.to_string(), addr);
+        Ok(())
+    }
+
+    /// This method marks a window, moving the mark if another window had it (async)
+    ///
+    /// The mark is only moved once the tags are set
+    pub async fn mark_async(&self, label: &str, addr: Address) -> HResult<()> {
+        let dispatches = lock_state(&self.state).mark_dispatches(label, addr);
+        call_all_async(&dispatches).await?;
+        lock_state(&self.state)
+            .marks
+            .insert(label.to_string(), addr);
+        Ok(())
+    }
+
+    /// This method removes a mark, returning the window that had it (blocking)
+    ///
+    /// The mark is only removed once the tag is removed
+    pub fn unmark(&self, label: &str) -> HResult<Option<Address>> {
+        let (addr, dispatches) = lock_state(&self.state).unmark_dispatches(label);
+        call_all(&dispatches)?;
+        lock_state(&self.state).marks.remove(label);
+        Ok(addr)
+    }
+
+    /// This method removes a mark, returning the window that had it (async)
+    ///
+    /// The mark is only removed once the tag is removed
+    pub async fn unmark_async(&self, label: &str) -> HResult<Option<Address>> {
+        let (addr, dispatches) = lock_state(&self.state).unmark_dispatches(label);
+        call_all_async(&dispatches).await?;
+        lock_state(&self.state).marks.remove(label);
+        Ok(addr)
+    }
+
+    /// This method focuses the window with a mark (blocking)
+    pub fn jump(&self, label: &str) -> HResult<Address> {
+        let addr = self.get(label).ok_or_else(|| Self::unknown(label))?;
+        Dispatch::call(DispatchType::FocusWindow(WindowIdentifier::Address(addr)))?;
+        Ok(addr)
+    }
+
+    /// This method focuses the window with a mark (async)
+    pub async fn jump_async(&self, label: &str) -> HResult<Address> {
+        let addr = self.get(label).ok_or_else(|| Self::unknown(label))?;
+        Dispatch::call_async(DispatchType::FocusWindow(WindowIdentifier::Address(addr))).await?;
+        Ok(addr)
+    }
+
+    fn marked_pair(&self, first: &str, second: &str, clients: Clients) -> HResult<Vec<Client>> {
+        let find = |label: &str| self.get(label).ok_or_else(|| Self::unknown(label));
+        let addrs = [find(first)?, find(second)?];
+        let clients: Vec<Client> = clients
+            .filter(|client| addrs.contains(&client.address))
+            .collect();
+        match clients.len() {
+            2 => Ok(clients),
+            _ => Err(Self::unknown(
+                if clients.iter().any(|c| c.address == addrs[0]) {
+                    second
+                } else {
+                    first
+                },
+            )),
+        }
+    }
+
+    /// This method swaps the workspaces, floating states and floating geometries of two
+    /// marked windows (blocking)
+    ///
+    /// Hyprland can't swap two arbitrary tiled windows on the same workspace,
+    /// so this returns an error for them
+    pub fn swap(&self, first: &str, second: &str) -> HResult<()> {
+        let clients = self.marked_pair(first, second, Clients::get()?)?;
+        batch_all(&swap_dispatches(&clients[0], &clients[1])?)
+    }
+
+    /// This method swaps the workspaces, floating states and floating geometries of two
+    /// marked windows (async)
+    ///
+    /// Hyprland can't swap two arbitrary tiled windows on the same workspace,
+    /// so this returns an error for them
+    pub async fn swap_async(&self, first: &str, second: &str) -> HResult<()> {
+        let clients = self.marked_pair(first, second, Clients::get_async().await?)?;
+        batch_all_async(&swap_dispatches(&clients[0], &clients[1])?).await
+    }
+
+    fn track_close(&self, addr: Address) {
+        lock_state(&self.state)
+            .marks
+            .retain(|_, marked| *marked != addr);
+    }
+
+    /// This method adds the handler that removes the marks of closed windows to a listener
+    pub fn attach(&self, listener: &mut EventListener) {
+        let marks = self.clone();
+        listener.add_window_close_handler(move |addr| marks.track_close(addr));
+    }
+
+    /// This method adds the handler that removes the marks of closed windows to a mutable
+    /// listener
+    pub fn attach_mutable(&self, listener: &mut EventListenerMutable) {
+        let marks = self.clone();
+        listener.add_window_close_handler(move |addr, _| marks.track_close(addr));
+    }
+}
+
+#[test]
+fn test_marks() {
+    let (first, second) = (Address::from(0x10), Address::from(0x20));
+    let recorder = Recorder::start();
+    let marks = Marks::new().use_tags(true);
+    let results = [
+        marks.mark("a", first),
+        marks.mark("b", first),
+        marks.mark("a", second),
+        marks.unmark("b").map(|_| ()),
+        marks.jump("a").map(|_| ()),
+    ];
+    for result in results {
+        if let Err(e) = result {
+            panic!("Error occured: {e}");
+        }
+    }
+    assert_eq!(marks.marks(), vec![("a".to_string(), second)]);
+    assert!(marks.jump("b").is_err());
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "dispatch tagwindow +mark_a address:0x10",
+            "dispatch tagwindow +mark_b address:0x10",
+            "dispatch tagwindow -mark_a address:0x10",
+            "dispatch tagwindow +mark_a address:0x20",
+            "dispatch tagwindow -mark_b address:0x10",
+            "dispatch focuswindow address:0x20",
+        ]
+    );
+    // the marks are kept as they were if the tags can't be changed
+    recorder.clear();
+    recorder.respond_with("Invalid tag");
+    assert!(marks.mark("a", first).is_err());
+    recorder.respond_with("Invalid tag");
+    assert!(marks.unmark("a").is_err());
+    assert_eq!(marks.marks(), vec![("a".to_string(), second)]);
+    marks.track_close(second);
+    assert!(marks.marks().is_empty());
+
+    let local = Marks::new().use_tags(false);
+    recorder.clear();
+    if let Err(e) = local.mark("a", first) {
+        panic!("Error occured: {e}");
+    }
+    assert_eq!(local.labels(first), vec!["a".to_string()]);
+    assert!(recorder.commands().is_empty());
+
+    let client = |addr: Address, work: WorkspaceId, floating| {
+        super::TestClient::new(addr)
+            .geometry((10, 20), (300, 200))
+            .workspace(work)
+            .floating(floating)
+            .tags(&["mark_term*", "other"])
+            .build()
+    };
+    let mut state = MarksState::default();
+    state.load(vec![client(first, 1, false)]);
+    assert_eq!(state.marks.get("term"), Some(&first));
+    if let Err(e) = local.mark("b", second) {
+        panic!("Error occured: {e}");
+    }
+    recorder.clear();
+    recorder.respond_with(super::clients_reply(&[
+        &client(first, 1, false),
+        &client(second, 2, true),
+    ]));
+    if let Err(e) = local.swap("a", "b") {
+        panic!("Error occured: {e}");
+    }
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "j/clients",
+            "[[BATCH]]dispatch movetoworkspacesilent 2,address:0x10;dispatch movetoworkspacesilent 1,address:0x20;dispatch setfloating address:0x10;dispatch resizewindowpixel exact 300 200,address:0x10;dispatch movewindowpixel exact 10 20,address:0x10;dispatch settiled address:0x20",
+        ]
+    );
+    // nothing is dispatched for two tiled windows on the same workspace
+    recorder.clear();
+    recorder.respond_with(super::clients_reply(&[
+        &client(first, 1, false),
+        &client(second, 1, false),
+    ]));
+    assert!(local.swap("a", "b").is_err());
+    assert_eq!(recorder.commands(), vec!["j/clients"]);
+}
+
+#[test]
+fn test_marks_without_tags() {
+    // Hyprland versions before 0.37 don't send the tags
+    let mut client = match serde_json::to_value(Client {
+        tags: Some(vec![]),
+        ..Client::default()
+    }) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    if let serde_json::Value::Object(fields) = &mut client {
+        fields.remove("tags");
+    }
+    let clients: Vec<Client> = match crate::data::parse_data("clients", format!("[{client}]")) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(clients[0].tags, None);
+    let mut state = MarksState::default();
+    state.load(clients);
+    assert!(state.marks.is_empty());
+}
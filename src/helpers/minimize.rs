@@ -0,0 +1,462 @@
+//! Minimize emulation, by moving windows to a special workspace
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::HResult;
+//! # fn main() -> HResult<()> {
+//! use hyprland::event_listener::EventListener;
+//! use hyprland::helpers::{minimize::Minimizer, print_errors};
+//! let minimizer = Minimizer::persistent("/tmp/minimized.json")?;
+//! minimizer.prune()?;
+//! let mut listener = EventListener::new();
+//! minimizer.attach(&mut listener, print_errors("minimizer"));
+//! for window in minimizer.minimized() {
+//!     println!("{} is minimized", window.title);
+//! }
+//! listener.start_listener()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use crate::data::Clients;
+use crate::dispatch::{OwnedWorkspaceIdentifier, WorkspaceIdentifier};
+use crate::event_listener::{EventListener, EventListenerMutable, MinimizeEventData};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// This struct holds a minimized window, and where to restore it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MinimizedWindow {
+    /// The window address
+    pub address: Address,
+    /// The window class
+    pub class: String,
+    /// The window title
+    pub title: String,
+    /// The workspace the window was on
+    pub workspace: WorkspaceRef,
+    /// Was the window floating?
+    pub floating: bool,
+    /// The geometry of the window (restored if it was floating)
+    pub geometry: Geometry,
+}
+
+impl From<&Client> for MinimizedWindow {
+    fn from(client: &Client) -> Self {
+        Self {
+            address: client.address,
+            class: client.class.clone(),
+            title: client.title.clone(),
+            workspace: client.workspace_ref(),
+            floating: client.floating,
+            geometry: client.geometry(),
+        }
+    }
+}
+
+impl MinimizedWindow {
+    fn restore(&self) -> Vec<OwnedDispatchType> {
+        let win = || Some(WindowIdentifier::Address(self.address));
+        let mut dispatches = vec![OwnedDispatchType::MoveToWorkspaceSilent(
+            OwnedWorkspaceIdentifier::from(WorkspaceIdentifier::from(&self.workspace)),
+            win(),
+        )];
+        if self.floating {
+            dispatches.push(OwnedDispatchType::SetFloating(win()));
+            dispatches.extend(place_window(self.address, self.geometry));
+        } else {
+            dispatches.push(OwnedDispatchType::SetTiled(win()));
+        }
+        dispatches.push(OwnedDispatchType::FocusWindow(WindowIdentifier::Address(
+            self.address,
+        )));
+        dispatches
+    }
+}
+
+#[derive(Debug)]
+struct MinimizerState {
+    name: String,
+    windows: Vec<MinimizedWindow>,
+    path: Option<PathBuf>,
+}
+
+impl MinimizerState {
+    fn save(&self) -> HResult<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        // the file is replaced at once, so it is never left half written
+        let mut temp = path.clone().into_os_string();
+        temp.push(".tmp");
+        std::fs::write(&temp, serde_json::to_vec(&self.windows)?)?;
+        Ok(std::fs::rename(&temp, path)?)
+    }
+
+    /// Returns [None] if the window is already minimized, so where it was isn't overwritten
+    /// with the special workspace
+    fn hide(&self, client: &Client) -> Option<OwnedDispatchType> {
+        if self.find(Some(client.address)).is_some() {
+            return None;
+        }
+        Some(OwnedDispatchType::MoveToWorkspaceSilent(
+            OwnedWorkspaceIdentifier::Special(Some(self.name.clone())),
+            Some(WindowIdentifier::Address(client.address)),
+        ))
+    }
+
+    /// Remembers where a window was, once it was hidden
+    fn remember(&mut self, client: &Client) -> HResult<()> {
+        if self.find(Some(client.address)).is_none() {
+            self.windows.push(client.into());
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Finds a minimized window, [None] being the last one
+    fn find(&self, addr: Option<Address>) -> Option<&MinimizedWindow> {
+        match addr {
+            Some(addr) => self.windows.iter().find(|window| window.address == addr),
+            None => self.windows.last(),
+        }
+    }
+
+    /// Forgets a window, once it was restored or closed
+    fn forget(&mut self, addr: Address) -> HResult<()> {
+        let len = self.windows.len();
+        self.windows.retain(|window| window.address != addr);
+        if self.windows.len() != len {
+            self.save()?;
+        }
+        Ok(())
+    }
+}
+
+/// This struct minimizes windows, by moving them to a special workspace,
+/// and remembers where they were so they can be restored
+///
+/// The handlers added with [Minimizer::attach] react to minimize requests from the windows
+/// themselves (eg. from a taskbar), and forget closed windows
+#[derive(Debug, Clone)]
+pub struct Minimizer {
+    state: Arc<Mutex<MinimizerState>>,
+}
+
+impl Default for Minimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Minimizer {
+    /// Creates a minimizer using the `special:minimized` workspace, without persistence
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MinimizerState {
+                name: "minimized".to_string(),
+                windows: vec![],
+                path: None,
+            })),
+        }
+    }
+
+    /// Creates a minimizer that saves its state to a json file,
+    /// loading the windows minimized by a previous run if it exists
+    pub fn persistent<P: AsRef<Path>>(path: P) -> HResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let windows = match std::fs::read(&path) {
+            Ok(data) => serde_json::from_slice(&data)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(error) => return Err(error.into()),
+        };
+        let minimizer = Self::new();
+        {
+            let mut state = lock_state(&minimizer.state);
+            state.windows = windows;
+            state.path = Some(path);
+        }
+        Ok(minimizer)
+    }
+
+    /// Sets the name of the special workspace minimized windows are moved to
+    pub fn workspace_name<T: ToString>(self, name: T) -> Self {
+        lock_state(&self.state).name = name.to_string();
+        self
+    }
+
+    fn not_found(addr: Address) -> HyprError {
+        HyprError::IoError(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("There is no window with the address {addr}"),
+        ))
+    }
+
+    /// This method returns the minimized windows, in the order they were minimized
+    pub fn minimized(&self) -> Vec<MinimizedWindow> {
+        lock_state(&self.state).windows.clone()
+    }
+
+    /// This method returns true if the window is minimized
+    pub fn is_minimized(&self, addr: Address) -> bool {
+        lock_state(&self.state)
+            .windows
+            .iter()
+            .any(|window| window.address == addr)
+    }
+
+    /// This method minimizes a window, doing nothing if it already is (blocking)
+    pub fn minimize(&self, addr: Address) -> HResult<()> {
+        let client = Clients::get()?
+            .find(|client| client.address == addr)
+            .ok_or_else(|| Self::not_found(addr))?;
+        let dispatch = lock_state(&self.state).hide(&client);
+        if let Some(dispatch) = dispatch {
+            Dispatch::call(dispatch.as_borrowed())?;
+            lock_state(&self.state).remember(&client)?;
+        }
+        Ok(())
+    }
+
+    /// This method minimizes a window, doing nothing if it already is (async)
+    pub async fn minimize_async(&self, addr: Address) -> HResult<()> {
+        let client = Clients::get_async()
+            .await?
+            .find(|client| client.address == addr)
+            .ok_or_else(|| Self::not_found(addr))?;
+        let dispatch = lock_state(&self.state).hide(&client);
+        if let Some(dispatch) = dispatch {
+            Dispatch::call_async(dispatch.as_borrowed()).await?;
+            lock_state(&self.state).remember(&client)?;
+        }
+        Ok(())
+    }
+
+    /// This method restores a minimized window to where it was, returning false if it
+    /// wasn't minimized (blocking)
+    ///
+    /// The window stays in the list if it couldn't be restored
+    pub fn restore(&self, addr: Address) -> HResult<bool> {
+        let window = lock_state(&self.state).find(Some(addr)).cloned();
+        match window {
+            Some(window) => self.restore_window(&window).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// This method restores a minimized window to where it was, returning false if it
+    /// wasn't minimized (async)
+    ///
+    /// The window stays in the list if it couldn't be restored
+    pub async fn restore_async(&self, addr: Address) -> HResult<bool> {
+        let window = lock_state(&self.state).find(Some(addr)).cloned();
+        match window {
+            Some(window) => self.restore_window_async(&window).await.map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// This method restores the last minimized window (blocking)
+    pub fn restore_last(&self) -> HResult<Option<Address>> {
+        let window = lock_state(&self.state).find(None).cloned();
+        match window {
+            Some(window) => self.restore_window(&window).map(|_| Some(window.address)),
+            None => Ok(None),
+        }
+    }
+
+    /// This method restores the last minimized window (async)
+    pub async fn restore_last_async(&self) -> HResult<Option<Address>> {
+        let window = lock_state(&self.state).find(None).cloned();
+        match window {
+            Some(window) => self
+                .restore_window_async(&window)
+                .await
+                .map(|_| Some(window.address)),
+            None => Ok(None),
+        }
+    }
+
+    fn restore_window(&self, window: &MinimizedWindow) -> HResult<()> {
+        call_all(&window.restore())?;
+        lock_state(&self.state).forget(window.address)
+    }
+
+    async fn restore_window_async(&self, window: &MinimizedWindow) -> HResult<()> {
+        call_all_async(&window.restore()).await?;
+        lock_state(&self.state).forget(window.address)
+    }
+
+    /// This method forgets the minimized windows that were closed (eg. while the program
+    /// using the minimizer wasn't running)
+    pub fn prune(&self) -> HResult<()> {
+        let clients: Vec<Address> = Clients::get()?.map(|client| client.address).collect();
+        self.retain_existing(&clients)
+    }
+
+    /// This method forgets the minimized windows that were closed (async)
+    pub async fn prune_async(&self) -> HResult<()> {
+        let clients: Vec<Address> = Clients::get_async()
+            .await?
+            .map(|client| client.address)
+            .collect();
+        self.retain_existing(&clients)
+    }
+
+    fn retain_existing(&self, clients: &[Address]) -> HResult<()> {
+        let mut state = lock_state(&self.state);
+        state
+            .windows
+            .retain(|window| clients.contains(&window.address));
+        state.save()
+    }
+
+    fn track_request(&self, data: MinimizeEventData) -> HResult<()> {
+        let MinimizeEventData(addr, minimize) = data;
+        if minimize {
+            self.minimize(addr)
+        } else {
+            self.restore(addr).map(|_| ())
+        }
+    }
+
+    fn track_close(&self, addr: Address) -> HResult<()> {
+        lock_state(&self.state).forget(addr)
+    }
+
+    /// This method adds the handlers that react to minimize requests to a listener
+    ///
+    /// The errors of the requests made by the handlers are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach(&self, listener: &mut EventListener, on_error: impl Fn(HyprError) + 'static) {
+        let on_error = Rc::new(on_error);
+        let (minimizer, report) = (self.clone(), on_error.clone());
+        listener.add_minimize_handler(move |data| {
+            handler_error(&*report, minimizer.track_request(data))
+        });
+        let minimizer = self.clone();
+        listener.add_window_close_handler(move |addr| {
+            handler_error(&*on_error, minimizer.track_close(addr))
+        });
+    }
+
+    /// This method adds the handlers that react to minimize requests to a mutable listener
+    ///
+    /// The errors of the requests made by the handlers are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach_mutable(
+        &self,
+        listener: &mut EventListenerMutable,
+        on_error: impl Fn(HyprError) + 'static,
+    ) {
+        let on_error = Rc::new(on_error);
+        let (minimizer, report) = (self.clone(), on_error.clone());
+        listener.add_minimize_handler(move |data, _| {
+            handler_error(&*report, minimizer.track_request(data))
+        });
+        let minimizer = self.clone();
+        listener.add_window_close_handler(move |addr, _| {
+            handler_error(&*on_error, minimizer.track_close(addr))
+        });
+    }
+}
+
+#[test]
+fn test_minimizer() {
+    let path =
+        std::env::temp_dir().join(format!("hyprland-rs-minimizer-{}.json", std::process::id()));
+    let minimizer = match Minimizer::persistent(&path) {
+        Ok(v) => v.workspace_name("hidden"),
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let floating = super::TestClient::new(0x10)
+        .geometry((100, 200), (640, 480))
+        .workspace(2)
+        .floating(true)
+        .build();
+    let tiled = super::TestClient::new(0x20)
+        .workspace_named(5, "code")
+        .build();
+
+    let recorder = Recorder::start();
+    let clients = super::clients_reply(&[&floating, &tiled]);
+    // it is only remembered once it was hidden
+    recorder
+        .respond_with(&clients)
+        .respond_with("No such window found");
+    assert!(minimizer.minimize(floating.address).is_err());
+    assert!(!minimizer.is_minimized(floating.address));
+    recorder.clear();
+    for client in [&floating, &tiled] {
+        recorder.respond_with(&clients);
+        if let Err(e) = minimizer.minimize(client.address) {
+            panic!("Error occured: {e}");
+        }
+    }
+    assert!(minimizer.is_minimized(tiled.address));
+    // minimizing it again keeps where it was
+    let hidden = super::TestClient::new(0x20)
+        .workspace_named(-98, "special:hidden")
+        .build();
+    recorder.respond_with(super::clients_reply(&[&floating, &hidden]));
+    if let Err(e) = minimizer.minimize(hidden.address) {
+        panic!("Error occured: {e}");
+    }
+    assert_eq!(
+        minimizer.minimized()[1].workspace,
+        WorkspaceRef::Name("code".to_string())
+    );
+
+    // the state survives a restart
+    let minimizer = match Minimizer::persistent(&path) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(minimizer.minimized().len(), 2);
+    assert_eq!(
+        minimizer.minimized()[0].geometry,
+        Geometry::new(100, 200, 640, 480)
+    );
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "j/clients",
+            "dispatch movetoworkspacesilent special:hidden,address:0x10",
+            "j/clients",
+            "dispatch movetoworkspacesilent special:hidden,address:0x20",
+            "j/clients",
+        ]
+    );
+    // a window that failed to be restored stays minimized
+    recorder.respond_with("No such window found");
+    assert!(minimizer.restore(tiled.address).is_err());
+    assert!(minimizer.is_minimized(tiled.address));
+    recorder.clear();
+    let restored = [
+        minimizer.restore_last(),
+        minimizer.restore(floating.address).map(|_| None),
+        minimizer.restore_last(),
+    ];
+    for result in restored {
+        if let Err(e) = result {
+            panic!("Error occured: {e}");
+        }
+    }
+    assert!(minimizer.minimized().is_empty());
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "dispatch movetoworkspacesilent name:code,address:0x20",
+            "dispatch settiled address:0x20",
+            "dispatch focuswindow address:0x20",
+            "dispatch movetoworkspacesilent 2,address:0x10",
+            "dispatch setfloating address:0x10",
+            "dispatch resizewindowpixel exact 640 480,address:0x10",
+            "dispatch movewindowpixel exact 100 200,address:0x10",
+            "dispatch focuswindow address:0x10",
+        ]
+    );
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,216 @@
+use crate::data::Client;
+use crate::dispatch::{Dispatch, DispatchType, OwnedDispatchType, Position, WindowIdentifier};
+use crate::shared::*;
+use regex::Regex;
+
+/// This module provides the run-or-raise helper
+pub mod run_or_raise;
+
+/// This module provides dropdown windows kept in special workspaces
+pub mod scratchpad;
+
+/// This module provides minimize emulation, with a list of windows to restore
+pub mod minimize;
+
+/// This module provides snapping presets for floating windows
+pub mod snap;
+
+/// This module provides a manager for picture-in-picture windows
+pub mod pip;
+
+/// This module provides workspaces numbered per monitor
+pub mod workspace_sets;
+
+/// This module provides i3 style window marks
+pub mod marks;
+
+/// This struct matches clients by their class and title, using regular expressions
+///
+/// A matcher without any pattern matches every client
+#[derive(Debug, Clone, Default)]
+pub struct WindowMatcher {
+    class: Option<Regex>,
+    title: Option<Regex>,
+}
+
+fn compile(pattern: &str) -> HResult<Regex> {
+    Regex::new(pattern)
+        .map_err(|error| HyprError::ParseError(format!("invalid pattern {pattern:?}: {error}")))
+}
+
+/// A missing pattern matches everything
+fn matches_pattern(pattern: &Option<Regex>, text: &str) -> bool {
+    // not `Option::is_none_or`, which needs Rust 1.82
+    match pattern {
+        Some(re) => re.is_match(text),
+        None => true,
+    }
+}
+
+impl WindowMatcher {
+    /// Creates a matcher that matches every client
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pattern the class has to match
+    pub fn class(mut self, pattern: &str) -> HResult<Self> {
+        self.class = Some(compile(pattern)?);
+        Ok(self)
+    }
+
+    /// Sets the pattern the title has to match
+    pub fn title(mut self, pattern: &str) -> HResult<Self> {
+        self.title = Some(compile(pattern)?);
+        Ok(self)
+    }
+
+    /// This method returns true if the class and title match their patterns
+    pub fn matches_parts(&self, class: &str, title: &str) -> bool {
+        matches_pattern(&self.class, class) && matches_pattern(&self.title, title)
+    }
+
+    /// This method returns true if the client matches the patterns
+    pub fn matches(&self, client: &Client) -> bool {
+        self.matches_parts(&client.class, &client.title)
+    }
+}
+
+/// Calls dispatchers in order, stopping at the first error (blocking)
+pub(crate) fn call_all(dispatches: &[OwnedDispatchType]) -> HResult<()> {
+    for dispatch in dispatches {
+        Dispatch::call(dispatch.as_borrowed())?;
+    }
+    Ok(())
+}
+
+/// Calls dispatchers in order, stopping at the first error (async)
+pub(crate) async fn call_all_async(dispatches: &[OwnedDispatchType]) -> HResult<()> {
+    for dispatch in dispatches {
+        Dispatch::call_async(dispatch.as_borrowed()).await?;
+    }
+    Ok(())
+}
+
+/// Calls dispatchers in a single batch (blocking)
+pub(crate) fn batch_all(dispatches: &[OwnedDispatchType]) -> HResult<()> {
+    let dispatches: Vec<DispatchType> = dispatches.iter().map(|d| d.as_borrowed()).collect();
+    Dispatch::batch(&dispatches)
+}
+
+/// Calls dispatchers in a single batch (async)
+pub(crate) async fn batch_all_async(dispatches: &[OwnedDispatchType]) -> HResult<()> {
+    let dispatches: Vec<DispatchType> = dispatches.iter().map(|d| d.as_borrowed()).collect();
+    Dispatch::batch_async(&dispatches).await
+}
+
+/// This function returns a error callback for `attach`, which prints the errors of a helper
+///
+/// Handlers can't return errors to the listener, so the errors of their requests are given
+/// to the callback passed to `attach`, and the listener keeps going, leaving the windows as
+/// the failed request found them
+pub fn print_errors(helper: &'static str) -> impl Fn(HyprError) {
+    move |e| eprintln!("Hyprland-rs {helper} handler failed: {e}")
+}
+
+/// Passes the error of a request made by an event handler to the error callback
+pub(crate) fn handler_error<T>(on_error: &dyn Fn(HyprError), result: HResult<T>) {
+    if let Err(e) = result {
+        on_error(e);
+    }
+}
+
+/// The dispatchers that give a floating window an exact geometry
+pub(crate) fn place_window(addr: Address, area: Geometry) -> [OwnedDispatchType; 2] {
+    let clamp = |value: i32| value.clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+    [
+        OwnedDispatchType::ResizeWindowPixel(
+            Position::Exact(clamp(area.width), clamp(area.height)),
+            WindowIdentifier::Address(addr),
+        ),
+        OwnedDispatchType::MoveWindowPixel(
+            Position::Exact(clamp(area.x), clamp(area.y)),
+            WindowIdentifier::Address(addr),
+        ),
+    ]
+}
+
+/// A [Client] builder for the tests, every field it doesn't set is the default
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub(crate) struct TestClient(Client);
+
+#[cfg(test)]
+impl TestClient {
+    /// A window on workspace 1
+    pub(crate) fn new(addr: impl Into<Address>) -> Self {
+        Self(Client {
+            address: addr.into(),
+            ..Client::default()
+        })
+        .workspace(1)
+    }
+    pub(crate) fn workspace(self, id: WorkspaceId) -> Self {
+        self.workspace_named(id, &id.to_string())
+    }
+    pub(crate) fn workspace_named(mut self, id: WorkspaceId, name: &str) -> Self {
+        self.0.workspace = crate::data::WorkspaceBasic {
+            id,
+            name: name.to_string(),
+        };
+        self
+    }
+    pub(crate) fn class(mut self, class: &str) -> Self {
+        self.0.class = class.to_string();
+        self
+    }
+    pub(crate) fn title(mut self, title: &str) -> Self {
+        self.0.title = title.to_string();
+        self
+    }
+    pub(crate) fn floating(mut self, floating: bool) -> Self {
+        self.0.floating = floating;
+        self
+    }
+    pub(crate) fn geometry(mut self, at: (i16, i16), size: (u16, u16)) -> Self {
+        self.0.at = at;
+        self.0.size = size;
+        self
+    }
+    pub(crate) fn focus_history_id(mut self, id: Option<i32>) -> Self {
+        self.0.focus_history_id = id;
+        self
+    }
+    pub(crate) fn tags(mut self, tags: &[&str]) -> Self {
+        self.0.tags = Some(tags.iter().map(|tag| tag.to_string()).collect());
+        self
+    }
+    pub(crate) fn build(self) -> Client {
+        self.0
+    }
+}
+
+/// The `j/clients` reply listing the clients, to script a [Recorder]
+#[cfg(test)]
+pub(crate) fn clients_reply(clients: &[&Client]) -> String {
+    match serde_json::to_string(clients) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    }
+}
+
+#[test]
+fn test_window_matcher() {
+    let matcher = match WindowMatcher::new()
+        .class("^firefox$")
+        .and_then(|matcher| matcher.title("Picture-in-Picture"))
+    {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert!(matcher.matches_parts("firefox", "Picture-in-Picture"));
+    assert!(!matcher.matches_parts("firefox-nightly", "Picture-in-Picture"));
+    assert!(!matcher.matches_parts("firefox", "Mozilla Firefox"));
+    assert!(WindowMatcher::new().matches_parts("any", ""));
+    assert!(WindowMatcher::new().class("(").is_err());
+}
@@ -0,0 +1,406 @@
+//! Keeping picture-in-picture windows floating and pinned in a corner of the focused monitor
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::HResult;
+//! # fn main() -> HResult<()> {
+//! use hyprland::event_listener::EventListener;
+//! use hyprland::helpers::{pip::*, print_errors, WindowMatcher};
+//! let pip = PictureInPicture::new(WindowMatcher::new().title("^Picture-in-Picture$")?)
+//!     .corner(Corner::BottomRight)
+//!     .size(0.25, 0.25);
+//! pip.scan()?;
+//! let mut listener = EventListener::new();
+//! pip.attach(&mut listener, print_errors("picture-in-picture"));
+//! listener.start_listener()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use crate::data::{Clients, CursorPosition, Monitor, Monitors};
+use crate::event_listener::{
+    EventListener, EventListenerMutable, MonitorEventData, WindowOpenEvent,
+};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+/// This enum holds the corners of a monitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner
+    TopLeft,
+    /// The top right corner
+    TopRight,
+    /// The bottom left corner
+    BottomLeft,
+    /// The bottom right corner
+    BottomRight,
+}
+
+impl Corner {
+    /// This method returns the corner on the other side horizontally
+    pub fn mirrored(self) -> Self {
+        match self {
+            Corner::TopLeft => Corner::TopRight,
+            Corner::TopRight => Corner::TopLeft,
+            Corner::BottomLeft => Corner::BottomRight,
+            Corner::BottomRight => Corner::BottomLeft,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PipState {
+    matcher: WindowMatcher,
+    corner: Corner,
+    size: (f32, f32),
+    margin: i32,
+    windows: Vec<Address>,
+}
+
+/// This struct keeps the windows matching a [WindowMatcher] floating, pinned, and in a corner
+/// of the focused monitor
+///
+/// The handlers added with [PictureInPicture::attach] pick up new windows, and move the windows
+/// along when another monitor gets focused. When the cursor is in the corner, the windows are
+/// put in the corner on the other side.
+#[derive(Debug, Clone)]
+pub struct PictureInPicture {
+    state: Arc<Mutex<PipState>>,
+}
+
+impl PictureInPicture {
+    /// Creates a manager for the windows matching, in the bottom right corner,
+    /// sized to a quarter of the width and height of the monitor
+    pub fn new(matcher: WindowMatcher) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(PipState {
+                matcher,
+                corner: Corner::BottomRight,
+                size: (0.25, 0.25),
+                margin: 20,
+                windows: vec![],
+            })),
+        }
+    }
+
+    /// Sets the corner the windows are put in
+    pub fn corner(self, corner: Corner) -> Self {
+        lock_state(&self.state).corner = corner;
+        self
+    }
+
+    /// Sets the size of the windows, as fractions of the usable area of the monitor
+    pub fn size(self, width: f32, height: f32) -> Self {
+        lock_state(&self.state).size = (width, height);
+        self
+    }
+
+    /// Sets the space between the windows and the edges of the usable area of the monitor
+    pub fn margin(self, margin: i32) -> Self {
+        lock_state(&self.state).margin = margin;
+        self
+    }
+
+    /// This method returns the windows being managed
+    pub fn windows(&self) -> Vec<Address> {
+        lock_state(&self.state).windows.clone()
+    }
+
+    /// This method returns the corner and geometry windows get on a monitor,
+    /// avoiding the corner the cursor is in
+    pub fn area(&self, monitor: &Monitor, cursor: Option<(i32, i32)>) -> (Corner, Geometry) {
+        let state = lock_state(&self.state);
+        let usable = monitor.usable_area();
+        let usable = usable.inset(state.margin, state.margin, state.margin, state.margin);
+        let width = (usable.width as f32 * state.size.0).round() as i32;
+        let height = (usable.height as f32 * state.size.1).round() as i32;
+        let place = |corner: Corner| {
+            let x = match corner {
+                Corner::TopLeft | Corner::BottomLeft => usable.x,
+                Corner::TopRight | Corner::BottomRight => usable.x + usable.width - width,
+            };
+            let y = match corner {
+                Corner::TopLeft | Corner::TopRight => usable.y,
+                Corner::BottomLeft | Corner::BottomRight => usable.y + usable.height - height,
+            };
+            Geometry::new(x, y, width, height)
+        };
+        let area = place(state.corner);
+        match cursor {
+            Some((x, y)) if area.contains(x, y) => {
+                (state.corner.mirrored(), place(state.corner.mirrored()))
+            }
+            _ => (state.corner, area),
+        }
+    }
+
+    fn adopt_dispatches(
+        &self,
+        client: &Client,
+        monitor: &Monitor,
+        cursor: Option<(i32, i32)>,
+    ) -> Vec<OwnedDispatchType> {
+        {
+            let mut state = lock_state(&self.state);
+            if !state.windows.contains(&client.address) {
+                state.windows.push(client.address);
+            }
+        }
+        let win = WindowIdentifier::Address(client.address);
+        let mut dispatches = vec![];
+        if !client.floating {
+            dispatches.push(OwnedDispatchType::SetFloating(Some(win.clone())));
+        }
+        if !client.pinned {
+            dispatches.push(OwnedDispatchType::PinWindow(win));
+        }
+        dispatches.extend(place_window(client.address, self.area(monitor, cursor).1));
+        dispatches
+    }
+
+    fn arrange_dispatches(
+        &self,
+        monitor: &Monitor,
+        cursor: Option<(i32, i32)>,
+    ) -> Vec<OwnedDispatchType> {
+        let area = self.area(monitor, cursor).1;
+        self.windows()
+            .into_iter()
+            .flat_map(|addr| place_window(addr, area))
+            .collect()
+    }
+
+    /// This method starts managing the windows matching that are already open (blocking)
+    pub fn scan(&self) -> HResult<()> {
+        let monitor = Monitor::get_active()?;
+        let cursor = cursor(CursorPosition::get()?);
+        let matcher = lock_state(&self.state).matcher.clone();
+        let dispatches: Vec<OwnedDispatchType> = Clients::get()?
+            .filter(|client| matcher.matches(client))
+            .flat_map(|client| self.adopt_dispatches(&client, &monitor, cursor))
+            .collect();
+        batch_all(&dispatches)
+    }
+
+    /// This method starts managing the windows matching that are already open (async)
+    pub async fn scan_async(&self) -> HResult<()> {
+        let monitor = Monitor::get_active_async().await?;
+        let cursor = cursor(CursorPosition::get_async().await?);
+        let matcher = lock_state(&self.state).matcher.clone();
+        let dispatches: Vec<OwnedDispatchType> = Clients::get_async()
+            .await?
+            .filter(|client| matcher.matches(client))
+            .flat_map(|client| self.adopt_dispatches(&client, &monitor, cursor))
+            .collect();
+        batch_all_async(&dispatches).await
+    }
+
+    /// This method moves the windows to a monitor (blocking)
+    pub fn arrange(&self, monitor: &Monitor) -> HResult<()> {
+        let cursor = cursor(CursorPosition::get()?);
+        batch_all(&self.arrange_dispatches(monitor, cursor))
+    }
+
+    /// This method moves the windows to a monitor (async)
+    pub async fn arrange_async(&self, monitor: &Monitor) -> HResult<()> {
+        let cursor = cursor(CursorPosition::get_async().await?);
+        batch_all_async(&self.arrange_dispatches(monitor, cursor)).await
+    }
+
+    fn track_open(&self, window: WindowOpenEvent) -> HResult<()> {
+        let WindowOpenEvent(addr, _, class, title) = window;
+        if !lock_state(&self.state)
+            .matcher
+            .matches_parts(&class, &title)
+        {
+            return Ok(());
+        }
+        let Some(client) = Clients::get()?.find(|client| client.address == addr) else {
+            return Ok(());
+        };
+        let monitor = Monitor::get_active()?;
+        let cursor = cursor(CursorPosition::get()?);
+        batch_all(&self.adopt_dispatches(&client, &monitor, cursor))
+    }
+
+    fn track_monitor(&self, data: MonitorEventData) -> HResult<()> {
+        let MonitorEventData(target, _) = data;
+        if lock_state(&self.state).windows.is_empty() {
+            return Ok(());
+        }
+        match Monitors::get()?.find(|monitor| monitor.matches(&target)) {
+            Some(monitor) => self.arrange(&monitor),
+            None => Ok(()),
+        }
+    }
+
+    fn track_close(&self, addr: Address) {
+        lock_state(&self.state)
+            .windows
+            .retain(|window| *window != addr);
+    }
+
+    /// This method adds the handlers that manage the windows to a listener
+    ///
+    /// The errors of the requests made by the handlers are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach(&self, listener: &mut EventListener, on_error: impl Fn(HyprError) + 'static) {
+        let on_error = Rc::new(on_error);
+        let (pip, report) = (self.clone(), on_error.clone());
+        listener
+            .add_window_open_handler(move |window| handler_error(&*report, pip.track_open(window)));
+        let pip = self.clone();
+        listener.add_active_monitor_change_handler(move |data| {
+            handler_error(&*on_error, pip.track_monitor(data))
+        });
+        let pip = self.clone();
+        listener.add_window_close_handler(move |addr| pip.track_close(addr));
+    }
+
+    /// This method adds the handlers that manage the windows to a mutable listener
+    ///
+    /// The errors of the requests made by the handlers are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach_mutable(
+        &self,
+        listener: &mut EventListenerMutable,
+        on_error: impl Fn(HyprError) + 'static,
+    ) {
+        let on_error = Rc::new(on_error);
+        let (pip, report) = (self.clone(), on_error.clone());
+        listener.add_window_open_handler(move |window, _| {
+            handler_error(&*report, pip.track_open(window))
+        });
+        let pip = self.clone();
+        listener.add_active_monitor_change_handler(move |data, _| {
+            handler_error(&*on_error, pip.track_monitor(data))
+        });
+        let pip = self.clone();
+        listener.add_window_close_handler(move |addr, _| pip.track_close(addr));
+    }
+}
+
+fn cursor(position: CursorPosition) -> Option<(i32, i32)> {
+    Some((
+        i32::try_from(position.x).ok()?,
+        i32::try_from(position.y).ok()?,
+    ))
+}
+
+#[test]
+fn test_picture_in_picture() {
+    let matcher = match WindowMatcher::new().title("^Picture-in-Picture$") {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let pip = PictureInPicture::new(matcher).size(0.2, 0.2).margin(10);
+    let monitor = Monitor {
+        x: 1920,
+        width: 1920,
+        height: 1080,
+        scale: 1.0,
+        reserved: (0, 30, 0, 0),
+        ..Monitor::default()
+    };
+    assert_eq!(
+        pip.area(&monitor, None),
+        (Corner::BottomRight, Geometry::new(3450, 864, 380, 206))
+    );
+    assert_eq!(
+        pip.area(&monitor, Some((3500, 1000))),
+        (Corner::BottomLeft, Geometry::new(1930, 864, 380, 206))
+    );
+
+    let monitor = Monitor {
+        name: "DP-1".to_string(),
+        focused: true,
+        ..monitor
+    };
+    let monitors = match serde_json::to_string(&[&monitor]) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let cursor = r#"{"x": 0, "y": 0}"#;
+    let client = super::TestClient::new(0x10)
+        .title("Picture-in-Picture")
+        .build();
+    let other = super::TestClient::new(0x20).title("Videos").build();
+    let recorder = Recorder::start();
+    let pip = pip.corner(Corner::TopLeft);
+    recorder
+        .respond_with(&monitors)
+        .respond_with(cursor)
+        .respond_with(super::clients_reply(&[&client, &other]));
+    if let Err(e) = pip.scan() {
+        panic!("Error occured: {e}");
+    }
+    assert_eq!(pip.windows(), vec![client.address]);
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "j/monitors",
+            "j/cursorpos",
+            "j/clients",
+            "[[BATCH]]dispatch setfloating address:0x10;dispatch pin address:0x10;dispatch resizewindowpixel exact 380 206,address:0x10;dispatch movewindowpixel exact 1930 40,address:0x10",
+        ]
+    );
+
+    // only the windows matching are picked up when they open
+    recorder.clear();
+    let open = |window: &Client| {
+        WindowOpenEvent(
+            window.address,
+            WorkspaceRef::Id(1),
+            window.class.clone(),
+            window.title.clone(),
+        )
+    };
+    assert!(pip.track_open(open(&other)).is_ok());
+    let opened = super::TestClient::new(0x30)
+        .title("Picture-in-Picture")
+        .floating(true)
+        .build();
+    recorder
+        .respond_with(super::clients_reply(&[&client, &other, &opened]))
+        .respond_with(&monitors)
+        .respond_with(cursor);
+    if let Err(e) = pip.track_open(open(&opened)) {
+        panic!("Error occured: {e}");
+    }
+    assert_eq!(pip.windows(), vec![client.address, opened.address]);
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "j/clients",
+            "j/monitors",
+            "j/cursorpos",
+            "[[BATCH]]dispatch pin address:0x30;dispatch resizewindowpixel exact 380 206,address:0x30;dispatch movewindowpixel exact 1930 40,address:0x30",
+        ]
+    );
+
+    // the windows follow the focused monitor, away from the cursor
+    recorder.clear();
+    recorder
+        .respond_with(&monitors)
+        .respond_with(r#"{"x": 2000, "y": 100}"#);
+    let focused = MonitorEventData(
+        MonitorRef::from("DP-1"),
+        WorkspaceType::Regular("1".to_string()),
+    );
+    if let Err(e) = pip.track_monitor(focused) {
+        panic!("Error occured: {e}");
+    }
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "j/monitors",
+            "j/cursorpos",
+            "[[BATCH]]dispatch resizewindowpixel exact 380 206,address:0x10;dispatch movewindowpixel exact 3450 40,address:0x10;dispatch resizewindowpixel exact 380 206,address:0x30;dispatch movewindowpixel exact 3450 40,address:0x30",
+        ]
+    );
+
+    pip.track_close(client.address);
+    pip.track_close(opened.address);
+    assert!(pip.windows().is_empty());
+}
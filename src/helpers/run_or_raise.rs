@@ -0,0 +1,170 @@
+//! Focuses a program's window, or starts the program if it has none
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::HResult;
+//! # fn main() -> HResult<()> {
+//! use hyprland::dispatch::Exec;
+//! use hyprland::helpers::{run_or_raise::run_or_raise, WindowMatcher};
+//! let matcher = WindowMatcher::new().class("^firefox$")?;
+//! run_or_raise(&matcher, &Exec::new("firefox"))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::WindowMatcher;
+use crate::data::{Client, Clients};
+use crate::dispatch::{Dispatch, DispatchType, Exec, WindowIdentifier};
+use crate::shared::*;
+use std::sync::Mutex;
+
+lazy_static! {
+    /// The windows [run_or_raise] saw focused, most recent first, for Hyprland versions
+    /// that don't report the focus history
+    static ref RECENT: Mutex<Vec<Address>> = Mutex::new(vec![]);
+}
+
+fn remember(addr: Option<Address>) -> Vec<Address> {
+    let mut recent = lock_state(&RECENT);
+    if let Some(addr) = addr {
+        recent.retain(|other| *other != addr);
+        recent.insert(0, addr);
+    }
+    recent.clone()
+}
+
+/// This enum holds what [run_or_raise] did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOrRaise {
+    /// An existing window was focused
+    Raised(Address),
+    /// No window matched, so the command was started
+    Spawned,
+}
+
+/// This function picks the window to focus
+///
+/// If the active window matches, the next match is picked (ordered by workspace, then address),
+/// so repeated calls cycle through every match. Otherwise the most recently focused match is
+/// picked, using `recent` (most recent first) for the clients without a `focus_history_id`.
+pub fn next_match(
+    matcher: &WindowMatcher,
+    clients: &[Client],
+    active: Option<Address>,
+    recent: &[Address],
+) -> Option<Address> {
+    let mut matches: Vec<&Client> = clients.iter().filter(|c| matcher.matches(c)).collect();
+    let current = active.and_then(|addr| matches.iter().position(|c| c.address == addr));
+    match current {
+        Some(_) if matches.len() > 1 => {
+            matches.sort_by_key(|c| (c.workspace.id, c.address));
+            let pos = matches
+                .iter()
+                .position(|c| Some(c.address) == active)
+                .unwrap_or_default();
+            Some(matches[(pos + 1) % matches.len()].address)
+        }
+        Some(pos) => Some(matches[pos].address),
+        None => matches
+            .iter()
+            .min_by_key(|c| {
+                let rank = match c.focus_history_id {
+                    Some(id) => usize::try_from(id).ok(),
+                    None => recent.iter().position(|addr| *addr == c.address),
+                };
+                (rank.is_none(), rank)
+            })
+            .map(|c| c.address),
+    }
+}
+
+/// This function focuses the next window matching, or runs the command if there is none (blocking)
+pub fn run_or_raise(matcher: &WindowMatcher, command: &Exec) -> HResult<RunOrRaise> {
+    let clients: Vec<Client> = Clients::get()?.collect();
+    let active = Client::get_active()?.map(|client| client.address);
+    match next_match(matcher, &clients, active, &remember(active)) {
+        Some(addr) => {
+            Dispatch::call(DispatchType::FocusWindow(WindowIdentifier::Address(addr)))?;
+            remember(Some(addr));
+            Ok(RunOrRaise::Raised(addr))
+        }
+        None => {
+            command.call()?;
+            Ok(RunOrRaise::Spawned)
+        }
+    }
+}
+
+/// This function focuses the next window matching, or runs the command if there is none (async)
+pub async fn run_or_raise_async(matcher: &WindowMatcher, command: &Exec) -> HResult<RunOrRaise> {
+    let clients: Vec<Client> = Clients::get_async().await?.collect();
+    let active = Client::get_active_async()
+        .await?
+        .map(|client| client.address);
+    match next_match(matcher, &clients, active, &remember(active)) {
+        Some(addr) => {
+            Dispatch::call_async(DispatchType::FocusWindow(WindowIdentifier::Address(addr)))
+                .await?;
+            remember(Some(addr));
+            Ok(RunOrRaise::Raised(addr))
+        }
+        None => {
+            command.call_async().await?;
+            Ok(RunOrRaise::Spawned)
+        }
+    }
+}
+
+#[test]
+fn test_next_match() {
+    let client = |addr: u64, class: &str, work: WorkspaceId, focus_history_id: Option<i32>| {
+        super::TestClient::new(addr)
+            .class(class)
+            .workspace(work)
+            .focus_history_id(focus_history_id)
+            .build()
+    };
+    let clients = vec![
+        client(1, "kitty", 2, Some(3)),
+        client(2, "firefox", 1, Some(0)),
+        client(3, "kitty", 1, Some(2)),
+        client(4, "kitty", 3, Some(1)),
+        client(5, "kitty", 3, Some(-1)),
+    ];
+    let matcher = match WindowMatcher::new().class("^kitty$") {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let next = |active: u64| next_match(&matcher, &clients, Some(Address::from(active)), &[]);
+
+    // not focused yet, the most recent one is raised
+    assert_eq!(next(2), Some(Address::from(4)));
+    // cycles by workspace
+    assert_eq!(next(3), Some(Address::from(1)));
+    assert_eq!(next(1), Some(Address::from(4)));
+    assert_eq!(next(4), Some(Address::from(5)));
+    assert_eq!(next(5), Some(Address::from(3)));
+
+    // without focusHistoryID, the windows seen focused are used
+    let old: Vec<Client> = clients
+        .iter()
+        .cloned()
+        .map(|client| Client {
+            focus_history_id: None,
+            ..client
+        })
+        .collect();
+    let recent = [Address::from(2), Address::from(3), Address::from(1)];
+    assert_eq!(
+        next_match(&matcher, &old, Some(Address::from(2)), &recent),
+        Some(Address::from(3))
+    );
+    assert_eq!(
+        next_match(&matcher, &old, Some(Address::from(2)), &[]),
+        Some(Address::from(1))
+    );
+    let matcher = match WindowMatcher::new().class("^foot$") {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(next_match(&matcher, &clients, None, &[]), None);
+}
@@ -0,0 +1,452 @@
+//! Named dropdown windows, kept hidden in special workspaces
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::HResult;
+//! # fn main() -> HResult<()> {
+//! use hyprland::event_listener::EventListener;
+//! use hyprland::helpers::{print_errors, scratchpad::*, WindowMatcher};
+//! let pads = Scratchpads::new();
+//! pads.add(
+//!     Scratchpad::new("term", "kitty --class dropterm", WindowMatcher::new().class("^dropterm$")?)
+//!         .size(0.8, 0.5)
+//!         .position(0.1, 0.05)
+//!         .hide_on_focus_loss(true),
+//! );
+//! let mut listener = EventListener::new();
+//! pads.attach(&mut listener, print_errors("scratchpad"));
+//! // bound to a key, eg. from another thread
+//! pads.toggle("term")?;
+//! listener.start_listener()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use crate::data::{Clients, Monitor};
+use crate::dispatch::{Exec, ExecRule, OwnedWorkspaceIdentifier, WorkspaceIdentifier};
+use crate::event_listener::{
+    EventListener, EventListenerMutable, WindowMoveEvent, WindowOpenEvent,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// This struct describes a scratchpad: a program whose window is toggled in and out of view
+#[derive(Debug, Clone)]
+pub struct Scratchpad {
+    name: String,
+    command: String,
+    matcher: WindowMatcher,
+    size: (f32, f32),
+    position: Option<(f32, f32)>,
+    hide_on_focus_loss: bool,
+}
+
+impl Scratchpad {
+    /// Creates a scratchpad, hidden in the `special:{name}` workspace,
+    /// whose window is started with the command and found with the matcher
+    pub fn new<N: ToString, C: ToString>(name: N, command: C, matcher: WindowMatcher) -> Self {
+        Self {
+            name: name.to_string(),
+            command: command.to_string(),
+            matcher,
+            size: (0.8, 0.6),
+            position: None,
+            hide_on_focus_loss: false,
+        }
+    }
+
+    /// Sets the size, as fractions of the usable area of the focused monitor
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Sets the position of the top left corner, as fractions of the usable area of the
+    /// focused monitor (the window is centered by default)
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Sets whether the window is hidden when another window gets focused
+    pub fn hide_on_focus_loss(mut self, enabled: bool) -> Self {
+        self.hide_on_focus_loss = enabled;
+        self
+    }
+
+    /// This method returns the special workspace the window is hidden in
+    pub fn workspace(&self) -> WorkspaceRef {
+        WorkspaceRef::Special(Some(self.name.clone()))
+    }
+
+    /// This method returns where the window is shown on a monitor
+    pub fn placement(&self, monitor: &Monitor) -> Geometry {
+        let area = monitor.usable_area();
+        let width = (area.width as f32 * self.size.0).round() as i32;
+        let height = (area.height as f32 * self.size.1).round() as i32;
+        let (x, y) = match self.position {
+            Some((x, y)) => (
+                area.x + (area.width as f32 * x).round() as i32,
+                area.y + (area.height as f32 * y).round() as i32,
+            ),
+            None => (
+                area.x + (area.width - width) / 2,
+                area.y + (area.height - height) / 2,
+            ),
+        };
+        Geometry::new(x, y, width, height)
+    }
+
+    fn spawn(&self, monitor: &Monitor) -> Exec {
+        let area = self.placement(monitor);
+        let clamp = |value: i32| value.clamp(0, u16::MAX.into()) as u16;
+        Exec::new(&self.command)
+            .rule(ExecRule::Float)
+            .rule(ExecRule::Size(clamp(area.width), clamp(area.height)))
+            .rule(ExecRule::Move(area.x, area.y))
+    }
+
+    fn show(&self, addr: Address, monitor: &Monitor) -> Vec<OwnedDispatchType> {
+        let work = WorkspaceRef::from(&monitor.active_workspace);
+        let mut dispatches = vec![
+            OwnedDispatchType::MoveToWorkspaceSilent(
+                OwnedWorkspaceIdentifier::from(WorkspaceIdentifier::from(&work)),
+                Some(WindowIdentifier::Address(addr)),
+            ),
+            OwnedDispatchType::SetFloating(Some(WindowIdentifier::Address(addr))),
+        ];
+        dispatches.extend(place_window(addr, self.placement(monitor)));
+        dispatches.push(OwnedDispatchType::FocusWindow(WindowIdentifier::Address(
+            addr,
+        )));
+        dispatches
+    }
+
+    fn hide(&self, addr: Address) -> OwnedDispatchType {
+        OwnedDispatchType::MoveToWorkspaceSilent(
+            OwnedWorkspaceIdentifier::Special(Some(self.name.clone())),
+            Some(WindowIdentifier::Address(addr)),
+        )
+    }
+}
+
+#[derive(Debug)]
+struct PadState {
+    pad: Scratchpad,
+    window: Option<Address>,
+    visible: bool,
+}
+
+/// This enum holds what toggling a scratchpad did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScratchpadAction {
+    /// The program was started, its window is picked up when it opens
+    Spawned,
+    /// The window was brought to the focused monitor
+    Shown(Address),
+    /// The window was hidden in its special workspace
+    Hidden(Address),
+}
+
+enum Plan {
+    Spawn(Exec),
+    Show(Address, Vec<OwnedDispatchType>),
+    Hide(Address, OwnedDispatchType),
+}
+
+/// This struct manages a set of [Scratchpad]s
+///
+/// Windows spawned by a scratchpad are picked up by the handlers added with
+/// [Scratchpads::attach], which also forget closed windows and hide scratchpads on focus loss
+#[derive(Debug, Clone, Default)]
+pub struct Scratchpads {
+    state: Arc<Mutex<HashMap<String, PadState>>>,
+}
+
+impl Scratchpads {
+    /// Creates an empty set of scratchpads
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method adds a scratchpad, replacing the one with the same name
+    pub fn add(&self, pad: Scratchpad) {
+        lock_state(&self.state).insert(
+            pad.name.clone(),
+            PadState {
+                pad,
+                window: None,
+                visible: false,
+            },
+        );
+    }
+
+    /// This method returns the window of a scratchpad, if it was spawned
+    pub fn window(&self, name: &str) -> Option<Address> {
+        lock_state(&self.state)
+            .get(name)
+            .and_then(|state| state.window)
+    }
+
+    fn unknown(name: &str) -> HyprError {
+        HyprError::IoError(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("There is no scratchpad named {name:?}"),
+        ))
+    }
+
+    fn plan(&self, name: &str, clients: Vec<Client>, monitor: Monitor) -> HResult<Plan> {
+        let mut pads = lock_state(&self.state);
+        let state = pads.get_mut(name).ok_or_else(|| Self::unknown(name))?;
+        let known = clients
+            .iter()
+            .position(|client| Some(client.address) == state.window);
+        // if the window was never seen, it may have been started before
+        let found = known.or_else(|| {
+            clients
+                .iter()
+                .position(|client| state.pad.matcher.matches(client))
+        });
+        let Some(client) = found.map(|pos| &clients[pos]) else {
+            state.window = None;
+            state.visible = false;
+            return Ok(Plan::Spawn(state.pad.spawn(&monitor)));
+        };
+        state.window = Some(client.address);
+        let on_screen = client.workspace.id == monitor.active_workspace.id;
+        if on_screen {
+            state.visible = false;
+            Ok(Plan::Hide(client.address, state.pad.hide(client.address)))
+        } else {
+            state.visible = true;
+            Ok(Plan::Show(
+                client.address,
+                state.pad.show(client.address, &monitor),
+            ))
+        }
+    }
+
+    /// This method shows the scratchpad on the focused monitor, hides it if it is already
+    /// shown there, or starts it if its window doesn't exist (blocking)
+    pub fn toggle(&self, name: &str) -> HResult<ScratchpadAction> {
+        let clients: Vec<Client> = Clients::get()?.collect();
+        let monitor = Monitor::get_active()?;
+        match self.plan(name, clients, monitor)? {
+            Plan::Spawn(exec) => {
+                exec.call()?;
+                Ok(ScratchpadAction::Spawned)
+            }
+            Plan::Show(addr, dispatches) => {
+                call_all(&dispatches)?;
+                Ok(ScratchpadAction::Shown(addr))
+            }
+            Plan::Hide(addr, dispatch) => {
+                Dispatch::call(dispatch.as_borrowed())?;
+                Ok(ScratchpadAction::Hidden(addr))
+            }
+        }
+    }
+
+    /// This method shows the scratchpad on the focused monitor, hides it if it is already
+    /// shown there, or starts it if its window doesn't exist (async)
+    pub async fn toggle_async(&self, name: &str) -> HResult<ScratchpadAction> {
+        let clients: Vec<Client> = Clients::get_async().await?.collect();
+        let monitor = Monitor::get_active_async().await?;
+        let plan = self.plan(name, clients, monitor)?;
+        match plan {
+            Plan::Spawn(exec) => {
+                exec.call_async().await?;
+                Ok(ScratchpadAction::Spawned)
+            }
+            Plan::Show(addr, dispatches) => {
+                call_all_async(&dispatches).await?;
+                Ok(ScratchpadAction::Shown(addr))
+            }
+            Plan::Hide(addr, dispatch) => {
+                Dispatch::call_async(dispatch.as_borrowed()).await?;
+                Ok(ScratchpadAction::Hidden(addr))
+            }
+        }
+    }
+
+    /// This method hides the scratchpad, if it is shown (blocking)
+    pub fn hide(&self, name: &str) -> HResult<()> {
+        let dispatch = self.take_visible(name)?;
+        match dispatch {
+            Some(dispatch) => Dispatch::call(dispatch.as_borrowed()),
+            None => Ok(()),
+        }
+    }
+
+    /// This method hides the scratchpad, if it is shown (async)
+    pub async fn hide_async(&self, name: &str) -> HResult<()> {
+        let dispatch = self.take_visible(name)?;
+        match dispatch {
+            Some(dispatch) => Dispatch::call_async(dispatch.as_borrowed()).await,
+            None => Ok(()),
+        }
+    }
+
+    fn take_visible(&self, name: &str) -> HResult<Option<OwnedDispatchType>> {
+        let mut pads = lock_state(&self.state);
+        let state = pads.get_mut(name).ok_or_else(|| Self::unknown(name))?;
+        match state.window {
+            Some(addr) if state.visible => {
+                state.visible = false;
+                Ok(Some(state.pad.hide(addr)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn track_open(&self, window: WindowOpenEvent) {
+        let WindowOpenEvent(addr, _, class, title) = window;
+        for state in lock_state(&self.state).values_mut() {
+            if state.window.is_none() && state.pad.matcher.matches_parts(&class, &title) {
+                state.window = Some(addr);
+                state.visible = true;
+                break;
+            }
+        }
+    }
+
+    fn track_move(&self, window: WindowMoveEvent) {
+        let WindowMoveEvent(addr, work) = window;
+        for state in lock_state(&self.state).values_mut() {
+            if state.window == Some(addr) {
+                state.visible = work != state.pad.workspace();
+            }
+        }
+    }
+
+    fn track_close(&self, addr: Address) {
+        for state in lock_state(&self.state).values_mut() {
+            if state.window == Some(addr) {
+                state.window = None;
+                state.visible = false;
+            }
+        }
+    }
+
+    fn track_focus(&self, addr: Option<Address>) -> HResult<()> {
+        let Some(addr) = addr else {
+            return Ok(());
+        };
+        let hide: Vec<OwnedDispatchType> = lock_state(&self.state)
+            .values_mut()
+            .filter(|state| state.pad.hide_on_focus_loss && state.visible)
+            .filter_map(|state| match state.window {
+                Some(window) if window != addr => {
+                    state.visible = false;
+                    Some(state.pad.hide(window))
+                }
+                _ => None,
+            })
+            .collect();
+        call_all(&hide)
+    }
+
+    /// This method adds the handlers that track the scratchpad windows to a listener
+    ///
+    /// The errors of the requests made by the handlers are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach(&self, listener: &mut EventListener, on_error: impl Fn(HyprError) + 'static) {
+        let pads = self.clone();
+        listener.add_window_open_handler(move |window| pads.track_open(window));
+        let pads = self.clone();
+        listener.add_window_moved_handler(move |window| pads.track_move(window));
+        let pads = self.clone();
+        listener.add_window_close_handler(move |addr| pads.track_close(addr));
+        let pads = self.clone();
+        listener.add_active_window_address_change_handler(move |addr| {
+            handler_error(&on_error, pads.track_focus(addr))
+        });
+    }
+
+    /// This method adds the handlers that track the scratchpad windows to a mutable listener
+    ///
+    /// The errors of the requests made by the handlers are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach_mutable(
+        &self,
+        listener: &mut EventListenerMutable,
+        on_error: impl Fn(HyprError) + 'static,
+    ) {
+        let pads = self.clone();
+        listener.add_window_open_handler(move |window, _| pads.track_open(window));
+        let pads = self.clone();
+        listener.add_window_moved_handler(move |window, _| pads.track_move(window));
+        let pads = self.clone();
+        listener.add_window_close_handler(move |addr, _| pads.track_close(addr));
+        let pads = self.clone();
+        listener.add_active_window_address_change_handler(move |addr, _| {
+            handler_error(&on_error, pads.track_focus(addr))
+        });
+    }
+}
+
+#[test]
+fn test_scratchpads() {
+    let matcher = match WindowMatcher::new().class("^dropterm$") {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let pad = Scratchpad::new("term", "kitty --class dropterm", matcher)
+        .size(0.5, 0.5)
+        .hide_on_focus_loss(true);
+    let monitor = Monitor {
+        x: 1920,
+        width: 3840,
+        height: 2160,
+        scale: 2.0,
+        reserved: (0, 40, 0, 0),
+        ..Monitor::default()
+    };
+    assert_eq!(monitor.usable_area(), Geometry::new(1920, 40, 1920, 1040));
+    assert_eq!(pad.placement(&monitor), Geometry::new(2400, 300, 960, 520));
+    assert_eq!(
+        pad.spawn(&monitor).to_string(),
+        "[float; size 960 520; move 2400 300] kitty --class dropterm"
+    );
+    assert_eq!(
+        pad.clone().position(0.0, 0.0).placement(&monitor),
+        Geometry::new(1920, 40, 960, 520)
+    );
+
+    let pads = Scratchpads::new();
+    pads.add(pad);
+    let addr = Address::from(0x10);
+    pads.track_open(WindowOpenEvent(
+        Address::from(0x20),
+        WorkspaceRef::Id(1),
+        "kitty".to_string(),
+        String::new(),
+    ));
+    assert_eq!(pads.window("term"), None);
+    pads.track_open(WindowOpenEvent(
+        addr,
+        WorkspaceRef::Id(1),
+        "dropterm".to_string(),
+        String::new(),
+    ));
+    assert_eq!(pads.window("term"), Some(addr));
+
+    let recorder = Recorder::start();
+    assert!(pads.track_focus(Some(addr)).is_ok());
+    assert!(pads.track_focus(Some(Address::from(0x20))).is_ok());
+    pads.track_move(WindowMoveEvent(
+        addr,
+        WorkspaceRef::Special(Some("term".to_string())),
+    ));
+    assert!(pads.track_focus(Some(Address::from(0x30))).is_ok());
+    assert_eq!(
+        recorder.commands(),
+        vec!["dispatch movetoworkspacesilent special:term,address:0x10"]
+    );
+    pads.track_close(addr);
+    assert_eq!(pads.window("term"), None);
+    assert!(matches!(
+        pads.hide("files"),
+        Err(HyprError::IoError(e)) if e.kind() == std::io::ErrorKind::NotFound
+    ));
+}
@@ -0,0 +1,380 @@
+//! Snapping floating windows to parts of their monitor
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::{HResult, HyprDataActiveOptional};
+//! # fn main() -> HResult<()> {
+//! use hyprland::data::Client;
+//! use hyprland::helpers::snap::{snap, SnapPreset};
+//! if let Some(client) = Client::get_active()? {
+//!     snap(&client, SnapPreset::LeftHalf)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use crate::data::{Monitor, Monitors};
+use crate::keyword::{Keyword, OptionValue};
+
+/// This struct holds a size for each side of a rectangle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sides {
+    /// The top side
+    pub top: i32,
+    /// The right side
+    pub right: i32,
+    /// The bottom side
+    pub bottom: i32,
+    /// The left side
+    pub left: i32,
+}
+
+impl Sides {
+    /// Creates sides that are all the same size
+    pub fn all(size: i32) -> Self {
+        Self {
+            top: size,
+            right: size,
+            bottom: size,
+            left: size,
+        }
+    }
+
+    /// This method parses the value of a gaps option, either a number or
+    /// 1 to 4 css style numbers (eg. `5 10`)
+    pub fn from_option(value: &OptionValue) -> HResult<Self> {
+        let invalid = || {
+            HyprError::InvalidOptionValue(format!("{} isn't a valid gap size", value.to_string()))
+        };
+        match value {
+            OptionValue::Int(size) => Ok(Self::all(i32::try_from(*size).map_err(|_| invalid())?)),
+            OptionValue::Float(size) => Ok(Self::all(size.round() as i32)),
+            OptionValue::String(sizes) => {
+                let sizes = sizes
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|size| !size.is_empty())
+                    .map(|size| size.parse::<i32>().map_err(|_| invalid()))
+                    .collect::<HResult<Vec<i32>>>()?;
+                match sizes[..] {
+                    [all] => Ok(Self::all(all)),
+                    [vertical, horizontal] => Ok(Self {
+                        top: vertical,
+                        right: horizontal,
+                        bottom: vertical,
+                        left: horizontal,
+                    }),
+                    [top, horizontal, bottom] => Ok(Self {
+                        top,
+                        right: horizontal,
+                        bottom,
+                        left: horizontal,
+                    }),
+                    [top, right, bottom, left] => Ok(Self {
+                        top,
+                        right,
+                        bottom,
+                        left,
+                    }),
+                    _ => Err(invalid()),
+                }
+            }
+        }
+    }
+}
+
+/// This struct holds the spacing Hyprland keeps around windows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Gaps {
+    /// The gaps between windows (`general:gaps_in`), applied to each window
+    pub inner: Sides,
+    /// The gaps between windows and the monitor edges (`general:gaps_out`)
+    pub outer: Sides,
+    /// The border size (`general:border_size`)
+    pub border: i32,
+}
+
+impl Gaps {
+    fn from_options(inner: Keyword, outer: Keyword, border: Keyword) -> HResult<Self> {
+        Ok(Self {
+            inner: Sides::from_option(&inner.value)?,
+            outer: Sides::from_option(&outer.value)?,
+            border: Sides::from_option(&border.value)?.top,
+        })
+    }
+
+    /// This function reads the gaps from the config (blocking)
+    pub fn get() -> HResult<Self> {
+        Self::from_options(
+            Keyword::get("general:gaps_in")?,
+            Keyword::get("general:gaps_out")?,
+            Keyword::get("general:border_size")?,
+        )
+    }
+
+    /// This function reads the gaps from the config (async)
+    pub async fn get_async() -> HResult<Self> {
+        Self::from_options(
+            Keyword::get_async("general:gaps_in").await?,
+            Keyword::get_async("general:gaps_out").await?,
+            Keyword::get_async("general:border_size").await?,
+        )
+    }
+}
+
+/// This enum holds the places a window can be snapped to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnapPreset {
+    /// The left half
+    LeftHalf,
+    /// The right half
+    RightHalf,
+    /// The top half
+    TopHalf,
+    /// The bottom half
+    BottomHalf,
+    /// The left third
+    LeftThird,
+    /// The middle third
+    CenterThird,
+    /// The right third
+    RightThird,
+    /// The left two thirds
+    LeftTwoThirds,
+    /// The right two thirds
+    RightTwoThirds,
+    /// The top left quarter
+    TopLeft,
+    /// The top right quarter
+    TopRight,
+    /// The bottom left quarter
+    BottomLeft,
+    /// The bottom right quarter
+    BottomRight,
+    /// The whole usable area
+    Maximize,
+    /// Centered, with a size as fractions of the usable area
+    Center(f32, f32),
+    /// A cell of a grid, which can span multiple columns and rows
+    Grid {
+        /// The number of columns of the grid
+        columns: u8,
+        /// The number of rows of the grid
+        rows: u8,
+        /// The column of the cell (0 being the left one)
+        column: u8,
+        /// The row of the cell (0 being the top one)
+        row: u8,
+        /// The number of columns the cell spans
+        column_span: u8,
+        /// The number of rows the cell spans
+        row_span: u8,
+    },
+}
+
+impl SnapPreset {
+    /// Creates a grid cell spanning a single column and row
+    pub fn cell(columns: u8, rows: u8, column: u8, row: u8) -> Self {
+        Self::Grid {
+            columns,
+            rows,
+            column,
+            row,
+            column_span: 1,
+            row_span: 1,
+        }
+    }
+
+    /// The grid cell of the preset, as `(columns, rows, column, row, column_span, row_span)`
+    fn as_cell(&self) -> (u8, u8, u8, u8, u8, u8) {
+        use SnapPreset::*;
+        match *self {
+            LeftHalf => (2, 1, 0, 0, 1, 1),
+            RightHalf => (2, 1, 1, 0, 1, 1),
+            TopHalf => (1, 2, 0, 0, 1, 1),
+            BottomHalf => (1, 2, 0, 1, 1, 1),
+            LeftThird => (3, 1, 0, 0, 1, 1),
+            CenterThird => (3, 1, 1, 0, 1, 1),
+            RightThird => (3, 1, 2, 0, 1, 1),
+            LeftTwoThirds => (3, 1, 0, 0, 2, 1),
+            RightTwoThirds => (3, 1, 1, 0, 2, 1),
+            TopLeft => (2, 2, 0, 0, 1, 1),
+            TopRight => (2, 2, 1, 0, 1, 1),
+            BottomLeft => (2, 2, 0, 1, 1, 1),
+            BottomRight => (2, 2, 1, 1, 1, 1),
+            Maximize | Center(..) => (1, 1, 0, 0, 1, 1),
+            Grid {
+                columns,
+                rows,
+                column,
+                row,
+                column_span,
+                row_span,
+            } => (columns, rows, column, row, column_span, row_span),
+        }
+    }
+
+    /// This method returns the geometry a window gets on a monitor
+    pub fn area(&self, monitor: &Monitor, gaps: &Gaps) -> Geometry {
+        let usable = monitor.usable_area();
+        if let Self::Center(width, height) = *self {
+            let area = usable.inset(
+                gaps.outer.left,
+                gaps.outer.top,
+                gaps.outer.right,
+                gaps.outer.bottom,
+            );
+            let width = (area.width as f32 * width).round() as i32;
+            let height = (area.height as f32 * height).round() as i32;
+            let (x, y) = area.center();
+            return Geometry::new(x - width / 2, y - height / 2, width, height).inset(
+                gaps.border,
+                gaps.border,
+                gaps.border,
+                gaps.border,
+            );
+        }
+        let (columns, rows, column, row, column_span, row_span) = self.as_cell();
+        let columns = i32::from(columns.max(1));
+        let rows = i32::from(rows.max(1));
+        let column = i32::from(column).min(columns - 1);
+        let row = i32::from(row).min(rows - 1);
+        let end_column = (column + i32::from(column_span.max(1))).min(columns);
+        let end_row = (row + i32::from(row_span.max(1))).min(rows);
+
+        // the edges are computed from the cell indexes, so cells line up without rounding gaps
+        let left = usable.x + usable.width * column / columns;
+        let right = usable.x + usable.width * end_column / columns;
+        let top = usable.y + usable.height * row / rows;
+        let bottom = usable.y + usable.height * end_row / rows;
+        let pick = |edge: bool, outer: i32, inner: i32| if edge { outer } else { inner };
+        Geometry::new(left, top, right - left, bottom - top)
+            .inset(
+                pick(column == 0, gaps.outer.left, gaps.inner.left),
+                pick(row == 0, gaps.outer.top, gaps.inner.top),
+                pick(end_column == columns, gaps.outer.right, gaps.inner.right),
+                pick(end_row == rows, gaps.outer.bottom, gaps.inner.bottom),
+            )
+            .inset(gaps.border, gaps.border, gaps.border, gaps.border)
+    }
+}
+
+fn snap_dispatches(client: &Client, area: Geometry) -> Vec<OwnedDispatchType> {
+    let mut dispatches = vec![];
+    if !client.floating {
+        dispatches.push(OwnedDispatchType::SetFloating(Some(
+            WindowIdentifier::Address(client.address),
+        )));
+    }
+    dispatches.extend(place_window(client.address, area));
+    dispatches
+}
+
+fn monitor_not_found(client: &Client) -> HyprError {
+    HyprError::IoError(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!("The monitor of {} wasn't found", client.address),
+    ))
+}
+
+/// This function floats a window (if needed) and snaps it to a part of its monitor (blocking)
+pub fn snap(client: &Client, preset: SnapPreset) -> HResult<()> {
+    let monitor = Monitors::get()?
+        .find(|monitor| monitor.id == client.monitor)
+        .ok_or_else(|| monitor_not_found(client))?;
+    let area = preset.area(&monitor, &Gaps::get()?);
+    batch_all(&snap_dispatches(client, area))
+}
+
+/// This function floats a window (if needed) and snaps it to a part of its monitor (async)
+pub async fn snap_async(client: &Client, preset: SnapPreset) -> HResult<()> {
+    let monitor = Monitors::get_async()
+        .await?
+        .find(|monitor| monitor.id == client.monitor)
+        .ok_or_else(|| monitor_not_found(client))?;
+    let area = preset.area(&monitor, &Gaps::get_async().await?);
+    batch_all_async(&snap_dispatches(client, area)).await
+}
+
+#[test]
+fn test_snap_presets() {
+    let monitor = Monitor {
+        width: 2000,
+        height: 1040,
+        scale: 1.0,
+        reserved: (0, 40, 0, 0),
+        ..Monitor::default()
+    };
+    let gaps = Gaps {
+        inner: Sides::all(5),
+        outer: Sides::all(20),
+        border: 2,
+    };
+    let area = |preset: SnapPreset| preset.area(&monitor, &gaps);
+    assert_eq!(area(SnapPreset::LeftHalf), Geometry::new(22, 62, 971, 956));
+    assert_eq!(
+        area(SnapPreset::RightHalf),
+        Geometry::new(1007, 62, 971, 956)
+    );
+    assert_eq!(
+        area(SnapPreset::BottomRight),
+        Geometry::new(1007, 547, 971, 471)
+    );
+    assert_eq!(area(SnapPreset::Maximize), Geometry::new(22, 62, 1956, 956));
+    assert_eq!(
+        area(SnapPreset::RightTwoThirds),
+        area(SnapPreset::Grid {
+            columns: 3,
+            rows: 1,
+            column: 1,
+            row: 0,
+            column_span: 2,
+            row_span: 1,
+        })
+    );
+    assert_eq!(
+        area(SnapPreset::Center(0.5, 0.5)),
+        Geometry::new(512, 302, 976, 476)
+    );
+
+    assert_eq!(
+        Sides::from_option(&OptionValue::String("5 10".to_string())).ok(),
+        Some(Sides {
+            top: 5,
+            right: 10,
+            bottom: 5,
+            left: 10
+        })
+    );
+    assert!(Sides::from_option(&OptionValue::String("a".to_string())).is_err());
+
+    let client = super::TestClient::new(0x10).build();
+    let monitors = match serde_json::to_string(&[&monitor]) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let recorder = Recorder::start();
+    recorder
+        .respond_with(monitors)
+        .respond_with(r#"{"option": "general:gaps_in", "int": 5}"#)
+        .respond_with(r#"{"option": "general:gaps_out", "custom": "20 20 20 20"}"#)
+        .respond_with(r#"{"option": "general:border_size", "int": 2}"#);
+    if let Err(e) = snap(&client, SnapPreset::LeftHalf) {
+        panic!("Error occured: {e}");
+    }
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "j/monitors",
+            "j/getoption general:gaps_in",
+            "j/getoption general:gaps_out",
+            "j/getoption general:border_size",
+            "[[BATCH]]dispatch setfloating address:0x10;dispatch resizewindowpixel exact 971 956,address:0x10;dispatch movewindowpixel exact 22 62,address:0x10",
+        ]
+    );
+
+    // a window on a monitor that is gone isn't moved
+    recorder.clear();
+    recorder.respond_with("[]");
+    assert!(snap(&client, SnapPreset::LeftHalf).is_err());
+    assert_eq!(recorder.commands(), vec!["j/monitors"]);
+}
@@ -0,0 +1,395 @@
+//! Workspaces numbered per monitor, like the split-monitor-workspaces plugin
+//!
+//! ```rust, no_run
+//! # use hyprland::shared::HResult;
+//! # fn main() -> HResult<()> {
+//! use hyprland::event_listener::EventListener;
+//! use hyprland::helpers::{print_errors, workspace_sets::WorkspaceSets};
+//! let sets = WorkspaceSets::new(10);
+//! sets.load()?;
+//! let mut listener = EventListener::new();
+//! sets.attach(&mut listener, print_errors("workspace sets"));
+//! // bound to super+3, eg. from another thread
+//! sets.focus_local(3)?;
+//! listener.start_listener()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use crate::data::{Monitor, Monitors, Workspace, Workspaces};
+use crate::dispatch::{OwnedMonitorIdentifier, WorkspaceIdentifier};
+use crate::event_listener::{EventListener, EventListenerMutable};
+use std::sync::{Arc, Mutex};
+
+/// This enum holds how the global workspaces of a set are identified
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WorkspaceNaming {
+    /// Workspaces are numbered one set after another (eg. 1-10 for the first monitor,
+    /// 11-20 for the second)
+    #[default]
+    Ids,
+    /// Workspaces are named after the monitor (eg. `DP-1:3`)
+    Names,
+}
+
+#[derive(Debug, Default)]
+struct SetsState {
+    per_monitor: u8,
+    naming: WorkspaceNaming,
+    focus_added: bool,
+    /// Monitor names, by set index, kept when monitors are removed
+    monitors: Vec<String>,
+}
+
+impl SetsState {
+    fn set_of(&mut self, monitor: &str) -> usize {
+        match self.monitors.iter().position(|name| name == monitor) {
+            Some(set) => set,
+            None => {
+                self.monitors.push(monitor.to_string());
+                self.monitors.len() - 1
+            }
+        }
+    }
+}
+
+/// This struct maps per monitor workspace indexes (eg. 1 to 10) to global workspaces
+///
+/// Each monitor gets its own set of workspaces, the first time it is seen. Sets are never
+/// reassigned, so removing and adding back a monitor gives it the same workspaces.
+#[derive(Debug, Clone)]
+pub struct WorkspaceSets {
+    state: Arc<Mutex<SetsState>>,
+}
+
+impl WorkspaceSets {
+    /// Creates sets of `per_monitor` workspaces, identified by id
+    pub fn new(per_monitor: u8) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(SetsState {
+                per_monitor: per_monitor.max(1),
+                ..SetsState::default()
+            })),
+        }
+    }
+
+    /// Sets how the global workspaces are identified
+    pub fn naming(self, naming: WorkspaceNaming) -> Self {
+        lock_state(&self.state).naming = naming;
+        self
+    }
+
+    /// Sets if monitors that are added get focused, and switched to their first workspace
+    /// (off by default)
+    pub fn focus_added(self, focus: bool) -> Self {
+        lock_state(&self.state).focus_added = focus;
+        self
+    }
+
+    fn assign(&self, monitors: impl IntoIterator<Item = Monitor>) {
+        let mut monitors: Vec<Monitor> = monitors.into_iter().collect();
+        monitors.sort_by_key(|monitor| monitor.id);
+        let mut state = lock_state(&self.state);
+        for monitor in monitors {
+            state.set_of(&monitor.name);
+        }
+    }
+
+    /// This method gives a set to each connected monitor, in the order of their ids (blocking)
+    pub fn load(&self) -> HResult<()> {
+        self.assign(Monitors::get()?);
+        Ok(())
+    }
+
+    /// This method gives a set to each connected monitor, in the order of their ids (async)
+    pub async fn load_async(&self) -> HResult<()> {
+        self.assign(Monitors::get_async().await?);
+        Ok(())
+    }
+
+    /// This method returns the monitors that have a set, in the order of the sets
+    pub fn monitors(&self) -> Vec<String> {
+        lock_state(&self.state).monitors.clone()
+    }
+
+    /// This method returns the global workspace of an index (starting at 1) on a monitor
+    ///
+    /// It can be used with dispatchers by converting it into a [WorkspaceIdentifier].
+    /// Monitors without a set (see [WorkspaceSets::load]) give an error.
+    pub fn translate(&self, monitor: &str, local: u8) -> HResult<WorkspaceRef> {
+        let state = lock_state(&self.state);
+        if local == 0 || local > state.per_monitor {
+            return Err(HyprError::ParseError(format!(
+                "{local} isn't a workspace index between 1 and {}",
+                state.per_monitor
+            )));
+        }
+        let Some(set) = state.monitors.iter().position(|name| name == monitor) else {
+            return Err(HyprError::IoError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("{monitor} has no workspace set"),
+            )));
+        };
+        Ok(match state.naming {
+            WorkspaceNaming::Ids => {
+                let id = set as WorkspaceId * WorkspaceId::from(state.per_monitor)
+                    + WorkspaceId::from(local);
+                WorkspaceRef::Id(id)
+            }
+            WorkspaceNaming::Names => WorkspaceRef::Name(format!("{monitor}:{local}")),
+        })
+    }
+
+    /// This method returns the monitor and index of a global workspace, if it is in a set
+    pub fn local(&self, workspace: &WorkspaceRef) -> Option<(String, u8)> {
+        let state = lock_state(&self.state);
+        match (state.naming, workspace) {
+            (WorkspaceNaming::Ids, WorkspaceRef::Id(id)) if *id > 0 => {
+                let per_monitor = WorkspaceId::from(state.per_monitor);
+                let set = usize::try_from((id - 1) / per_monitor).ok()?;
+                let local = u8::try_from((id - 1) % per_monitor + 1).ok()?;
+                Some((state.monitors.get(set)?.clone(), local))
+            }
+            (WorkspaceNaming::Names, WorkspaceRef::Name(name)) => {
+                let (monitor, local) = name.rsplit_once(':')?;
+                let local: u8 = local.parse().ok()?;
+                let known = state.monitors.iter().any(|name| name == monitor);
+                (known && local > 0 && local <= state.per_monitor)
+                    .then(|| (monitor.to_string(), local))
+            }
+            _ => None,
+        }
+    }
+
+    fn focus_dispatch(&self, monitor: &str, local: u8) -> HResult<OwnedDispatchType> {
+        let work = self.translate(monitor, local)?;
+        Ok(OwnedDispatchType::FocusWorkspaceOnCurrentMonitor(
+            WorkspaceIdentifier::from(&work).into(),
+        ))
+    }
+
+    fn move_dispatch(
+        &self,
+        monitor: &Monitor,
+        local: u8,
+        silent: bool,
+    ) -> HResult<OwnedDispatchType> {
+        let work = WorkspaceIdentifier::from(&self.translate(&monitor.name, local)?).into();
+        if silent {
+            Ok(OwnedDispatchType::MoveToWorkspaceSilent(work, None))
+        } else {
+            Ok(OwnedDispatchType::MoveToWorkspace(work, None))
+        }
+    }
+
+    /// This method switches to a workspace of the focused monitor (blocking)
+    ///
+    /// The workspace is brought back to the monitor if it was moved to another one
+    pub fn focus_local(&self, local: u8) -> HResult<()> {
+        let dispatch = self.focus_dispatch(&Monitor::get_active()?.name, local)?;
+        Dispatch::call(dispatch.as_borrowed())
+    }
+
+    /// This method switches to a workspace of the focused monitor (async)
+    ///
+    /// The workspace is brought back to the monitor if it was moved to another one
+    pub async fn focus_local_async(&self, local: u8) -> HResult<()> {
+        let dispatch = self.focus_dispatch(&Monitor::get_active_async().await?.name, local)?;
+        Dispatch::call_async(dispatch.as_borrowed()).await
+    }
+
+    /// This method moves the active window to a workspace of the focused monitor,
+    /// optionally without switching to it (blocking)
+    pub fn move_to_local(&self, local: u8, silent: bool) -> HResult<()> {
+        let dispatch = self.move_dispatch(&Monitor::get_active()?, local, silent)?;
+        Dispatch::call(dispatch.as_borrowed())
+    }
+
+    /// This method moves the active window to a workspace of the focused monitor,
+    /// optionally without switching to it (async)
+    pub async fn move_to_local_async(&self, local: u8, silent: bool) -> HResult<()> {
+        let dispatch = self.move_dispatch(&Monitor::get_active_async().await?, local, silent)?;
+        Dispatch::call_async(dispatch.as_borrowed()).await
+    }
+
+    /// The dispatchers that move the existing workspaces of a monitor's set back to it,
+    /// and focus it if [WorkspaceSets::focus_added] is set
+    fn reclaim_dispatches(
+        &self,
+        monitor: &str,
+        workspaces: impl IntoIterator<Item = Workspace>,
+    ) -> HResult<Vec<OwnedDispatchType>> {
+        let target = || OwnedMonitorIdentifier::Name(monitor.to_string());
+        let mut dispatches: Vec<OwnedDispatchType> = workspaces
+            .into_iter()
+            .filter(|work| work.monitor != monitor)
+            .filter(|work| {
+                self.local(&WorkspaceRef::from(work))
+                    .is_some_and(|(owner, _)| owner == monitor)
+            })
+            .map(|work| {
+                OwnedDispatchType::MoveWorkspaceToMonitor(
+                    WorkspaceIdentifier::from(&WorkspaceRef::from(&work)).into(),
+                    target(),
+                )
+            })
+            .collect();
+        if lock_state(&self.state).focus_added {
+            dispatches.push(OwnedDispatchType::FocusMonitor(target()));
+            dispatches.push(self.focus_dispatch(monitor, 1)?);
+        }
+        Ok(dispatches)
+    }
+
+    fn track_added(&self, monitor: MonitorRef) -> HResult<()> {
+        // sets are kept by name, events always name the monitor
+        let monitor = match monitor {
+            MonitorRef::Name(name) => name,
+            MonitorRef::Id(_) => match monitor.resolve()? {
+                Some(found) => found.name,
+                None => return Ok(()),
+            },
+        };
+        lock_state(&self.state).set_of(&monitor);
+        call_all(&self.reclaim_dispatches(&monitor, Workspaces::get()?)?)
+    }
+
+    /// This method adds the handler that gives new monitors a set to a listener
+    ///
+    /// A monitor that is added back gets the workspaces of its set back, and is focused if
+    /// [WorkspaceSets::focus_added] is set. Removed monitors keep their set, so nothing is done
+    /// when a monitor is removed.
+    /// The errors of the requests made by the handler are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach(&self, listener: &mut EventListener, on_error: impl Fn(HyprError) + 'static) {
+        let sets = self.clone();
+        listener.add_monitor_added_handler(move |monitor| {
+            handler_error(&on_error, sets.track_added(monitor))
+        });
+    }
+
+    /// This method adds the handler that gives new monitors a set to a mutable listener
+    ///
+    /// A monitor that is added back gets the workspaces of its set back, and is focused if
+    /// [WorkspaceSets::focus_added] is set. Removed monitors keep their set, so nothing is done
+    /// when a monitor is removed.
+    /// The errors of the requests made by the handler are passed to `on_error`
+    /// (eg. [print_errors](super::print_errors))
+    pub fn attach_mutable(
+        &self,
+        listener: &mut EventListenerMutable,
+        on_error: impl Fn(HyprError) + 'static,
+    ) {
+        let sets = self.clone();
+        listener.add_monitor_added_handler(move |monitor, _| {
+            handler_error(&on_error, sets.track_added(monitor))
+        });
+    }
+}
+
+#[test]
+fn test_workspace_sets() {
+    let monitor = |id, name: &str| Monitor {
+        id,
+        name: name.to_string(),
+        ..Monitor::default()
+    };
+    let translate =
+        |sets: &WorkspaceSets, monitor: &str, local| match sets.translate(monitor, local) {
+            Ok(v) => v,
+            Err(e) => panic!("Error occured: {e}"),
+        };
+
+    let sets = WorkspaceSets::new(10);
+    sets.assign(vec![monitor(1, "HDMI-A-1"), monitor(0, "eDP-1")]);
+    assert_eq!(sets.monitors(), vec!["eDP-1", "HDMI-A-1"]);
+    assert_eq!(translate(&sets, "eDP-1", 3), WorkspaceRef::Id(3));
+    assert_eq!(translate(&sets, "HDMI-A-1", 10), WorkspaceRef::Id(20));
+    assert!(sets.translate("eDP-1", 11).is_err());
+    assert!(sets.translate("eDP-1", 0).is_err());
+    // translating doesn't give unknown monitors a set
+    assert!(sets.translate("DP-3", 1).is_err());
+    assert_eq!(sets.monitors(), vec!["eDP-1", "HDMI-A-1"]);
+
+    // a new monitor gets the next set, even after the others were unplugged
+    sets.assign(vec![monitor(0, "DP-2")]);
+    sets.assign(vec![monitor(0, "DP-2"), monitor(1, "HDMI-A-1")]);
+    assert_eq!(translate(&sets, "DP-2", 1), WorkspaceRef::Id(21));
+    assert_eq!(translate(&sets, "HDMI-A-1", 1), WorkspaceRef::Id(11));
+    assert_eq!(
+        sets.local(&WorkspaceRef::Id(25)),
+        Some(("DP-2".to_string(), 5))
+    );
+    assert_eq!(sets.local(&WorkspaceRef::Id(31)), None);
+
+    let recorder = Recorder::start();
+    let dispatches = [
+        sets.focus_dispatch("DP-2", 2),
+        sets.move_dispatch(&monitor(0, "eDP-1"), 4, true),
+    ];
+    for dispatch in dispatches {
+        match dispatch.and_then(|dispatch| Dispatch::call(dispatch.as_borrowed())) {
+            Ok(v) => v,
+            Err(e) => panic!("Error occured: {e}"),
+        }
+    }
+    assert_eq!(
+        recorder.commands(),
+        vec![
+            "dispatch focusworkspaceoncurrentmonitor 22",
+            "dispatch movetoworkspacesilent 4"
+        ]
+    );
+
+    // the workspaces of eDP-1 moved to DP-2 while it was unplugged are moved back
+    let workspace = |id: WorkspaceId, monitor: &str| Workspace {
+        id,
+        name: id.to_string(),
+        monitor: monitor.to_string(),
+        ..Workspace::default()
+    };
+    let workspaces = vec![
+        workspace(2, "DP-2"),
+        workspace(3, "eDP-1"),
+        workspace(12, "DP-2"),
+        workspace(21, "DP-2"),
+    ];
+    let reclaim = |sets: &WorkspaceSets| {
+        recorder.clear();
+        match sets.reclaim_dispatches("eDP-1", workspaces.clone()) {
+            Ok(dispatches) => {
+                if let Err(e) = call_all(&dispatches) {
+                    panic!("Error occured: {e}");
+                }
+            }
+            Err(e) => panic!("Error occured: {e}"),
+        }
+        recorder.commands()
+    };
+    assert_eq!(
+        reclaim(&sets),
+        vec!["dispatch moveworkspacetomonitor 2 eDP-1"]
+    );
+    // it is only focused if asked to
+    let sets = sets.focus_added(true);
+    assert_eq!(
+        reclaim(&sets),
+        vec![
+            "dispatch moveworkspacetomonitor 2 eDP-1",
+            "dispatch focusmonitor eDP-1",
+            "dispatch focusworkspaceoncurrentmonitor 1"
+        ]
+    );
+
+    let sets = WorkspaceSets::new(5).naming(WorkspaceNaming::Names);
+    sets.assign(vec![monitor(0, "DP-1")]);
+    assert_eq!(
+        translate(&sets, "DP-1", 2),
+        WorkspaceRef::Name("DP-1:2".to_string())
+    );
+    assert_eq!(
+        sets.local(&WorkspaceRef::Name("DP-1:2".to_string())),
+        Some(("DP-1".to_string(), 2))
+    );
+}
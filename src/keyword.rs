@@ -1,6 +1,6 @@
 //! # Keyword module
 //!
-//! This module is used for setting, and getting keywords
+//! This module is used for setting, and getting keywords, and describing every config option
 //!
 //! ## Usage
 //!
@@ -18,13 +18,17 @@
 use crate::shared::*;
 use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct OptionRaw {
     pub option: String,
-    pub int: i64,
-    pub float: f64,
-    pub str: String,
+    pub int: Option<i64>,
+    pub float: Option<f64>,
+    pub str: Option<String>,
+    pub custom: Option<String>,
+    pub set: Option<bool>,
 }
 
 /// This enum holds the possible values of a keyword/option
@@ -38,6 +42,7 @@ pub enum OptionValue {
     String(String),
 }
 
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for OptionValue {
     fn to_string(&self) -> String {
         match self {
@@ -104,6 +109,10 @@ pub struct Keyword {
     pub option: String,
     /// The value of the keyword/option
     pub value: OptionValue,
+    /// If the option was set by the user, [None] if Hyprland doesn't
+    /// report it (see [Capabilities::getoption_set])
+    #[serde(default)]
+    pub set: Option<bool>,
 }
 
 macro_rules! keyword {
@@ -113,32 +122,59 @@ macro_rules! keyword {
     (g $l:tt) => {
         format!("j/getoption {}", $l)
     };
+    (descriptions) => {
+        "j/descriptions".to_string()
+    };
 }
 
-fn parse_option_raw(opt: OptionRaw) -> OptionValue {
+fn parse_option_raw(opt: OptionRaw, caps: Capabilities) -> HResult<Keyword> {
     static HYPR_UNSET_FLOAT: f64 = -340282346638528859811704183484516925440.0;
     static HYPR_UNSET_INT: i64 = -9223372036854775807;
 
-    if opt.float == HYPR_UNSET_FLOAT {
-        if opt.int == HYPR_UNSET_INT {
-            OptionValue::String(opt.str)
-        } else {
-            OptionValue::Int(opt.int)
+    // older versions send all the fields, with the unused ones set to a sentinel,
+    // newer ones only send the field that is used
+    let unused_float = |float: f64| !caps.getoption_custom && float == HYPR_UNSET_FLOAT;
+    let unused_int = |int: i64| !caps.getoption_custom && int == HYPR_UNSET_INT;
+    let option = opt.option.clone();
+    // only used if the version is known to report it
+    let set = opt.set.filter(|_| caps.getoption_set);
+    let value = match opt {
+        OptionRaw {
+            custom: Some(custom),
+            ..
+        } => OptionValue::String(custom),
+        OptionRaw {
+            float: Some(float), ..
+        } if !unused_float(float) => OptionValue::Float(float),
+        OptionRaw { int: Some(int), .. } if !unused_int(int) => OptionValue::Int(int),
+        OptionRaw { str: Some(str), .. } => OptionValue::String(str),
+        OptionRaw { option, .. } => {
+            return Err(HyprError::ParseError(format!(
+                "getoption returned no value for {option}"
+            )))
         }
-    } else {
-        OptionValue::Float(opt.float)
+    };
+    Ok(Keyword { option, value, set })
+}
+
+fn check_set_reply(output: String) -> HResult<()> {
+    match output.trim() {
+        "ok" => Ok(()),
+        msg => Err(HyprError::NotOkKeyword(CommandError::parse(msg))),
     }
 }
 
 impl Keyword {
     /// This function sets a keyword's value
+    ///
+    /// If Hyprland doesn't accept it, a [HyprError::NotOkKeyword] error is returned
     pub fn set<Str: ToString, Opt: Into<OptionValue>>(key: Str, value: Opt) -> HResult<()> {
         let socket_path = get_socket_path(SocketType::Command);
-        let _ = write_to_socket_sync(
+        let output = write_to_socket_sync(
             socket_path,
             keyword!((key.to_string()), (value.into().to_string())).as_bytes(),
         )?;
-        Ok(())
+        check_set_reply(output)
     }
     /// This function sets a keyword's value (async)
     pub async fn set_async<Str: ToString, Opt: Into<OptionValue>>(
@@ -146,33 +182,335 @@ impl Keyword {
         value: Opt,
     ) -> HResult<()> {
         let socket_path = get_socket_path(SocketType::Command);
-        let _ = write_to_socket(
+        let output = write_to_socket(
             socket_path,
             keyword!((key.to_string()), (value.into().to_string())).as_bytes(),
         )
         .await?;
-        Ok(())
+        check_set_reply(output)
     }
     /// This function returns the value of a keyword
     pub fn get<Str: ToString>(key: Str) -> HResult<Self> {
         let socket_path = get_socket_path(SocketType::Command);
         let data = write_to_socket_sync(socket_path, keyword!(g(key.to_string())).as_bytes())?;
         let deserialized: OptionRaw = serde_json::from_str(&data)?;
-        let keyword = Keyword {
-            option: deserialized.option.clone(),
-            value: parse_option_raw(deserialized),
-        };
-        Ok(keyword)
+        parse_option_raw(deserialized, Capabilities::current())
     }
     /// This function returns the value of a keyword (async)
     pub async fn get_async<Str: ToString>(key: Str) -> HResult<Self> {
         let socket_path = get_socket_path(SocketType::Command);
         let data = write_to_socket(socket_path, keyword!(g(key.to_string())).as_bytes()).await?;
         let deserialized: OptionRaw = serde_json::from_str(&data)?;
-        let keyword = Keyword {
-            option: deserialized.option.clone(),
-            value: parse_option_raw(deserialized),
+        parse_option_raw(deserialized, Capabilities::current())
+    }
+}
+
+/// This enum holds the types of config options
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OptionType {
+    /// A boolean (set as a integer, or `true`/`false`)
+    Bool = 0,
+    /// A integer
+    Int = 1,
+    /// A floating point
+    Float = 2,
+    /// A short string
+    StringShort = 3,
+    /// A long string
+    StringLong = 4,
+    /// A color
+    Color = 5,
+    /// One of a list of choices
+    Choice = 6,
+    /// A gradient
+    Gradient = 7,
+    /// A 2D vector
+    Vector = 8,
+}
+
+/// This struct holds the type specific data of a option description
+///
+/// Values are kept as json, as their shape depends on the [OptionType]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct OptionDescriptionData {
+    /// The default value
+    pub default: Option<Value>,
+    /// The current value
+    pub current: Option<Value>,
+    /// The minimum value (numbers and vectors)
+    pub min: Option<Value>,
+    /// The maximum value (numbers and vectors)
+    pub max: Option<Value>,
+    /// The comma separated choices (choices)
+    pub options: Option<String>,
+    /// The index of the first choice (choices)
+    #[serde(rename = "firstIndex")]
+    pub first_index: Option<i64>,
+    /// If the option was explicitly set by the user
+    pub explicit: Option<bool>,
+}
+
+/// This struct holds the description of a config option
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptionDescription {
+    /// The name of the option (eg. `general:border_size`)
+    #[serde(rename = "value")]
+    pub option: String,
+    /// The description of the option
+    pub description: String,
+    /// The type of the option
+    #[serde(rename = "type")]
+    pub option_type: OptionType,
+    /// The option flags
+    #[serde(default)]
+    pub flags: u32,
+    /// The type specific data
+    #[serde(default)]
+    pub data: OptionDescriptionData,
+}
+
+fn json_to_option_value(value: &Value) -> Option<OptionValue> {
+    match value {
+        Value::Bool(bool) => Some(OptionValue::Int(*bool as i64)),
+        Value::Number(num) => match num.as_i64() {
+            Some(int) => Some(OptionValue::Int(int)),
+            None => num.as_f64().map(OptionValue::Float),
+        },
+        Value::String(str) => Some(OptionValue::String(str.clone())),
+        Value::Object(obj) => match (obj.get("x"), obj.get("y")) {
+            (Some(x), Some(y)) => Some(OptionValue::String(format!("{x} {y}"))),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl OptionDescription {
+    /// This method returns the default value of the option
+    pub fn default_value(&self) -> Option<OptionValue> {
+        self.data.default.as_ref().and_then(json_to_option_value)
+    }
+    /// This method returns the current value of the option, if Hyprland reports it
+    pub fn current_value(&self) -> Option<OptionValue> {
+        self.data.current.as_ref().and_then(json_to_option_value)
+    }
+    /// This method returns the choices of a [OptionType::Choice] option
+    pub fn choices(&self) -> Vec<String> {
+        match &self.data.options {
+            Some(options) => options
+                .split(',')
+                .map(|opt| opt.trim().to_string())
+                .collect(),
+            None => vec![],
+        }
+    }
+    /// This method returns the allowed range of a numeric option
+    pub fn range(&self) -> (Option<f64>, Option<f64>) {
+        (
+            self.data.min.as_ref().and_then(Value::as_f64),
+            self.data.max.as_ref().and_then(Value::as_f64),
+        )
+    }
+    /// This method checks if a value can be used for this option,
+    /// so it can be checked before calling [Keyword::set]
+    pub fn validate(&self, value: &OptionValue) -> HResult<()> {
+        let invalid = |reason: String| {
+            Err(HyprError::InvalidOptionValue(format!(
+                "{} can't be used for {}: {reason}",
+                value.to_string(),
+                self.option
+            )))
+        };
+        let check_range = |num: f64| {
+            let (min, max) = self.range();
+            if min.is_some_and(|min| num < min) || max.is_some_and(|max| num > max) {
+                invalid(format!("{num} is out of the range {min:?}..={max:?}"))
+            } else {
+                Ok(())
+            }
         };
-        Ok(keyword)
+        match (self.option_type, value) {
+            (OptionType::Bool, OptionValue::Int(0 | 1)) => Ok(()),
+            (OptionType::Bool, OptionValue::String(str)) => match str.as_str() {
+                "true" | "false" | "yes" | "no" | "on" | "off" | "0" | "1" => Ok(()),
+                _ => invalid("expected a boolean".to_string()),
+            },
+            (OptionType::Int, OptionValue::Int(int)) => check_range(*int as f64),
+            (OptionType::Float, OptionValue::Int(int)) => check_range(*int as f64),
+            (OptionType::Float, OptionValue::Float(float)) => check_range(*float),
+            // config values usually come as strings
+            (OptionType::Int, OptionValue::String(str)) => match str.trim().parse::<i64>() {
+                Ok(int) => check_range(int as f64),
+                Err(_) => invalid("expected an integer".to_string()),
+            },
+            (OptionType::Float, OptionValue::String(str)) => match str.trim().parse::<f64>() {
+                Ok(float) => check_range(float),
+                Err(_) => invalid("expected a number".to_string()),
+            },
+            (OptionType::Choice, OptionValue::Int(int)) => {
+                let first = self.data.first_index.unwrap_or_default();
+                let count = self.choices().len() as i64;
+                if *int >= first && *int < first + count {
+                    Ok(())
+                } else {
+                    invalid(format!("expected a choice index from {first}"))
+                }
+            }
+            (OptionType::Choice, OptionValue::String(str)) => {
+                if self.choices().contains(str) {
+                    Ok(())
+                } else {
+                    invalid(format!("expected one of {:?}", self.choices()))
+                }
+            }
+            (OptionType::Color, OptionValue::Int(_) | OptionValue::String(_)) => Ok(()),
+            (
+                OptionType::StringLong | OptionType::StringShort | OptionType::Gradient,
+                OptionValue::String(_),
+            ) => Ok(()),
+            (OptionType::Vector, OptionValue::String(str)) => {
+                let nums: Vec<_> = str.split_whitespace().map(str::parse::<f64>).collect();
+                if nums.len() == 2 && nums.iter().all(Result::is_ok) {
+                    Ok(())
+                } else {
+                    invalid("expected a vector (`x y`)".to_string())
+                }
+            }
+            (option_type, _) => invalid(format!("expected a value of type {option_type:?}")),
+        }
+    }
+}
+
+/// This struct holds the descriptions of every config option
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptionDescriptions(Vec<OptionDescription>);
+
+#[async_trait]
+impl HyprData for OptionDescriptions {
+    fn get() -> HResult<Self> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let data = write_to_socket_sync(socket_path, keyword!(descriptions).as_bytes())?;
+        Ok(serde_json::from_str(&data)?)
+    }
+    async fn get_async() -> HResult<Self> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let data = write_to_socket(socket_path, keyword!(descriptions).as_bytes()).await?;
+        Ok(serde_json::from_str(&data)?)
+    }
+}
+
+impl HyprDataVec<OptionDescription> for OptionDescriptions {
+    fn to_vec(self) -> Vec<OptionDescription> {
+        self.0
+    }
+}
+
+impl OptionDescriptions {
+    /// This method finds the description of a option
+    pub fn find(&self, option: &str) -> Option<&OptionDescription> {
+        self.0.iter().find(|desc| desc.option == option)
+    }
+    /// This method returns a iterator over all descriptions
+    pub fn iter(&self) -> std::slice::Iter<'_, OptionDescription> {
+        self.0.iter()
+    }
+    /// This method checks if a value can be used for a option, erroring if the option doesn't exist
+    pub fn validate<Opt: Into<OptionValue>>(&self, option: &str, value: Opt) -> HResult<()> {
+        match self.find(option) {
+            Some(desc) => desc.validate(&value.into()),
+            None => Err(HyprError::InvalidOptionValue(format!(
+                "{option} is not a config option"
+            ))),
+        }
+    }
+}
+
+#[test]
+fn test_option_descriptions() {
+    let data = r#"[
+        {"value": "general:border_size", "description": "size of the border around windows",
+         "type": 1, "flags": 0, "data": {"default": 1, "min": 0, "max": 20, "current": 2, "explicit": true}},
+        {"value": "general:layout", "description": "which layout to use",
+         "type": 3, "flags": 0, "data": {"default": "dwindle", "current": "dwindle", "explicit": false}},
+        {"value": "decoration:active_opacity", "description": "opacity of active windows",
+         "type": 2, "flags": 0, "data": {"default": 1.0, "min": 0.0, "max": 1.0, "current": 1.0, "explicit": false}}
+    ]"#;
+    let descriptions: OptionDescriptions = match serde_json::from_str(data) {
+        Ok(desc) => desc,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let border = descriptions.find("general:border_size");
+    assert!(matches!(
+        border.and_then(|d| d.default_value()),
+        Some(OptionValue::Int(1))
+    ));
+    assert!(descriptions.validate("general:border_size", 5).is_ok());
+    assert!(descriptions.validate("general:border_size", 50).is_err());
+    assert!(descriptions
+        .validate("general:border_size", "thick")
+        .is_err());
+    assert!(descriptions.validate("general:border_size", "5").is_ok());
+    assert!(descriptions.validate("general:border_size", "50").is_err());
+    assert!(descriptions
+        .validate("decoration:active_opacity", "0.5")
+        .is_ok());
+    assert!(matches!(
+        descriptions.find("general:layout").map(|d| d.option_type),
+        Some(OptionType::StringShort)
+    ));
+    assert!(descriptions.validate("general:layout", "master").is_ok());
+    assert!(descriptions
+        .validate("decoration:active_opacity", 0.5)
+        .is_ok());
+    assert!(descriptions.validate("general:not_an_option", 1).is_err());
+}
+
+#[test]
+fn test_keyword_set_reply() {
+    let recorder = Recorder::start();
+    recorder.respond_with("config option <general:foo> does not exist.");
+    match Keyword::set("general:foo", 1) {
+        Err(HyprError::NotOkKeyword(err)) => assert_eq!(err.kind, CommandErrorKind::InvalidKeyword),
+        other => panic!("Expected a keyword error, got {other:?}"),
+    }
+    assert!(Keyword::set("general:gaps_in", 5).is_ok());
+    assert_eq!(
+        recorder.commands(),
+        vec!["keyword general:foo 1", "keyword general:gaps_in 5"]
+    );
+}
+
+#[test]
+fn test_getoption_by_version() {
+    let raw = |data: &str| -> OptionRaw {
+        match serde_json::from_str(data) {
+            Ok(raw) => raw,
+            Err(e) => panic!("Error occured: {e}"),
+        }
+    };
+    let old = Capabilities::for_version(Some(HyprlandVersion::new(0, 25, 0)));
+    let new = Capabilities::for_version(Some(HyprlandVersion::new(0, 40, 0)));
+    let sentinels = r#"{"option": "general:gaps_in", "int": -9223372036854775807,
+        "float": 5.0, "str": "", "set": true}"#;
+    match parse_option_raw(raw(sentinels), old) {
+        Ok(keyword) => {
+            assert!(matches!(keyword.value, OptionValue::Float(float) if float == 5.0));
+            assert_eq!(keyword.set, None);
+        }
+        Err(e) => panic!("Error occured: {e}"),
+    }
+    let single = r#"{"option": "general:border_size", "int": -9223372036854775807, "set": true}"#;
+    match parse_option_raw(raw(single), new) {
+        Ok(keyword) => {
+            assert!(matches!(
+                keyword.value,
+                OptionValue::Int(-9223372036854775807)
+            ));
+            assert_eq!(keyword.set, Some(true));
+        }
+        Err(e) => panic!("Error occured: {e}"),
     }
+    assert!(parse_option_raw(raw(single), old).is_err());
 }
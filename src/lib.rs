@@ -31,12 +31,22 @@ pub mod dispatch;
 #[cfg(feature = "keyword")]
 pub mod keyword;
 
+/// This module provides the stuff needed to list, load and unload plugins
+#[cfg(feature = "plugin")]
+pub mod plugin;
+
 /// This module provides helpers to easily config Hyprland
 #[cfg(feature = "config")]
 pub mod config;
 
+/// This module provides higher level window management helpers, built on the other modules
+#[cfg(feature = "helpers")]
+pub mod helpers;
+
 /// The prelude module, this is to import all traits
 pub mod prelude {
+    #[cfg(feature = "data")]
+    pub use crate::data::HyprDataWithReport;
     pub use crate::shared::{HyprData, HyprDataActive, HyprDataActiveOptional, HyprDataVec};
 }
 
@@ -45,14 +55,32 @@ pub(crate) mod unix_async {
     pub use async_net::unix::UnixStream;
     #[cfg(feature = "async-std")]
     pub use async_std::{
-        io::{ReadExt, WriteExt},
+        io::{prelude::BufReadExt, BufReader, ReadExt, WriteExt},
         os::unix::net::UnixStream,
     };
     #[cfg(feature = "async-net")]
-    pub use futures_lite::io::{AsyncReadExt, AsyncWriteExt};
+    pub use futures_lite::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
     #[cfg(feature = "tokio")]
     pub use tokio::{
-        io::{AsyncReadExt, AsyncWriteExt},
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
         net::UnixStream,
     };
+
+    /// Runs a future, returning `None` if it didn't complete before the timeout
+    #[allow(dead_code)]
+    pub async fn with_timeout<F: std::future::Future>(
+        timeout: std::time::Duration,
+        future: F,
+    ) -> Option<F::Output> {
+        #[cfg(feature = "tokio")]
+        return tokio::time::timeout(timeout, future).await.ok();
+        #[cfg(feature = "async-std")]
+        return async_std::future::timeout(timeout, future).await.ok();
+        #[cfg(feature = "async-net")]
+        return futures_lite::future::or(async { Some(future.await) }, async {
+            async_io::Timer::after(timeout).await;
+            None
+        })
+        .await;
+    }
 }
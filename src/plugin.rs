@@ -0,0 +1,136 @@
+//! # Plugin module
+//!
+//! This module is used for listing, loading and unloading Hyprland plugins
+//!
+//! ## Usage
+//!
+//! ```rust, no_run
+//! use hyprland::shared::HResult;
+//! use hyprland::plugin::Plugins;
+//! use hyprland::prelude::*;
+//! fn main() -> HResult<()> {
+//!    Plugins::load("/path/to/plugin.so")?;
+//!    let plugins = Plugins::get()?.to_vec();
+//!    println!("{plugins:#?}");
+//!    Plugins::unload("/path/to/plugin.so")?;
+//!
+//!    Ok(())
+//! }
+//! ````
+
+use crate::shared::*;
+use serde::{Deserialize, Serialize};
+
+/// This struct holds information about a loaded plugin
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Plugin {
+    /// The plugin's name
+    pub name: String,
+    /// The plugin's author
+    pub author: String,
+    /// The handle of the loaded plugin
+    pub handle: Address,
+    /// The plugin's version
+    pub version: String,
+    /// The plugin's description
+    pub description: String,
+}
+
+/// This struct holds all loaded plugins
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Plugins(Vec<Plugin>);
+
+macro_rules! plugin {
+    (list) => {
+        "j/plugin list".to_string()
+    };
+    ($cmd:literal $path:tt) => {
+        format!("plugin {} {}", $cmd, $path)
+    };
+}
+
+fn parse_plugin_list(data: String) -> HResult<Plugins> {
+    // Hyprland replies with plain text when no plugins are loaded
+    match data.trim() {
+        "no plugins loaded" => Ok(Plugins(vec![])),
+        json if json.starts_with('[') => Ok(Plugins(serde_json::from_str(json)?)),
+        msg => Err(HyprError::NotOkPlugin(msg.to_string())),
+    }
+}
+
+fn check_plugin_response(response: String) -> HResult<()> {
+    match response.trim() {
+        "ok" => Ok(()),
+        msg => Err(HyprError::NotOkPlugin(msg.to_string())),
+    }
+}
+
+#[async_trait]
+impl HyprData for Plugins {
+    fn get() -> HResult<Self> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let data = write_to_socket_sync(socket_path, plugin!(list).as_bytes())?;
+        parse_plugin_list(data)
+    }
+    async fn get_async() -> HResult<Self> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let data = write_to_socket(socket_path, plugin!(list).as_bytes()).await?;
+        parse_plugin_list(data)
+    }
+}
+
+impl HyprDataVec<Plugin> for Plugins {
+    fn to_vec(self) -> Vec<Plugin> {
+        self.0
+    }
+}
+
+impl Plugins {
+    /// This function loads a plugin from a path to its shared object
+    pub fn load<Str: ToString>(path: Str) -> HResult<()> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let response =
+            write_to_socket_sync(socket_path, plugin!("load"(path.to_string())).as_bytes())?;
+        check_plugin_response(response)
+    }
+    /// This function loads a plugin from a path to its shared object (async)
+    pub async fn load_async<Str: ToString>(path: Str) -> HResult<()> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let response =
+            write_to_socket(socket_path, plugin!("load"(path.to_string())).as_bytes()).await?;
+        check_plugin_response(response)
+    }
+    /// This function unloads a plugin, using the path it was loaded from
+    pub fn unload<Str: ToString>(path: Str) -> HResult<()> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let response =
+            write_to_socket_sync(socket_path, plugin!("unload"(path.to_string())).as_bytes())?;
+        check_plugin_response(response)
+    }
+    /// This function unloads a plugin, using the path it was loaded from (async)
+    pub async fn unload_async<Str: ToString>(path: Str) -> HResult<()> {
+        let socket_path = get_socket_path(SocketType::Command);
+        let response =
+            write_to_socket(socket_path, plugin!("unload"(path.to_string())).as_bytes()).await?;
+        check_plugin_response(response)
+    }
+}
+
+#[test]
+fn test_plugin_list() {
+    let data = r#"[{"name": "hyprbars", "author": "Vaxry", "handle": "55d6ab6a4b50",
+        "version": "1.0", "description": "A plugin to add title bars to windows."}]"#;
+    let plugins = match parse_plugin_list(data.to_string()) {
+        Ok(plugins) => plugins.to_vec(),
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(plugins[0].name, "hyprbars");
+    assert_eq!(plugins[0].handle, Address::new(0x55d6ab6a4b50));
+    assert!(
+        matches!(parse_plugin_list("no plugins loaded".to_string()), Ok(Plugins(p)) if p.is_empty())
+    );
+    assert!(matches!(
+        parse_plugin_list("unknown request".to_string()),
+        Err(HyprError::NotOkPlugin(msg)) if msg == "unknown request"
+    ));
+}
@@ -16,7 +16,17 @@ pub enum HyprError {
     /// Error that occurs when parsing UTF-8 string
     FromUtf8Error(std::string::FromUtf8Error),
     /// Dispatcher returned non `ok` value
-    NotOkDispatch(String),
+    NotOkDispatch(CommandError),
+    /// Setting a keyword returned a non `ok` value
+    NotOkKeyword(CommandError),
+    /// A plugin command returned a non `ok` value (or a unexpected reply when listing plugins)
+    NotOkPlugin(String),
+    /// A value can't be used for a config option
+    InvalidOptionValue(String),
+    /// Error that occurs when a value could not be parsed from a string
+    ParseError(String),
+    /// The json sent by Hyprland doesn't match the structs of this crate (strict mode)
+    SchemaDrift(SchemaReport),
 }
 
 impl From<io::Error> for HyprError {
@@ -46,9 +56,18 @@ impl fmt::Display for HyprError {
                 Self::IoError(err) => err.to_string(),
                 Self::SerdeError(err) => err.to_string(),
                 Self::FromUtf8Error(err) => err.to_string(),
-                Self::NotOkDispatch(msg) => format!(
-                    "A dispatcher retrurned a non `ok`, value which is probably a error: {msg} was returned by it"
+                Self::NotOkDispatch(err) => format!(
+                    "A dispatcher retrurned a non `ok`, value which is probably a error: {err} was returned by it"
                 ),
+                Self::NotOkKeyword(err) => format!(
+                    "A keyword returned a non `ok` value, {err} was returned by it"
+                ),
+                Self::NotOkPlugin(msg) => format!(
+                    "A plugin command returned a non `ok` value, {msg} was returned by it"
+                ),
+                Self::InvalidOptionValue(msg) => format!("Invalid option value: {msg}"),
+                Self::ParseError(msg) => format!("A error occured while parsing: {msg}"),
+                Self::SchemaDrift(report) => format!("Schema drift detected: {report}"),
             }
         )
     }
@@ -56,14 +75,135 @@ impl fmt::Display for HyprError {
 
 impl error::Error for HyprError {}
 
+impl HyprError {
+    /// This method returns the error Hyprland replied with, if this is one
+    pub fn command_error(&self) -> Option<&CommandError> {
+        match self {
+            Self::NotOkDispatch(err) | Self::NotOkKeyword(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// This enum holds the kinds of errors Hyprland replies with when a command fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommandErrorKind {
+    /// The dispatcher doesn't exist
+    InvalidDispatcher,
+    /// A argument is invalid or missing
+    InvalidArgument,
+    /// No window matches the window selector
+    WindowNotFound,
+    /// The keyword (config option) doesn't exist
+    InvalidKeyword,
+    /// The command isn't permitted, or its target is locked
+    Locked,
+    /// Any other error
+    Other,
+}
+
+/// This struct holds a error Hyprland replied with to a command
+///
+/// Hyprland replies with human readable messages, so the kind is a best effort
+/// classification, the message is kept in `raw`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandError {
+    /// The kind of the error
+    pub kind: CommandErrorKind,
+    /// The reply sent by Hyprland
+    pub raw: String,
+}
+
+impl CommandError {
+    /// This method classifies a reply sent by Hyprland
+    pub fn parse<T: AsRef<str>>(raw: T) -> Self {
+        let raw = raw.as_ref().trim();
+        let msg = raw.to_lowercase();
+        let has = |needles: &[&str]| needles.iter().any(|needle| msg.contains(needle));
+        let not_found = [
+            "does not exist",
+            "doesn't exist",
+            "not found",
+            "no such",
+            "couldn't find",
+        ];
+
+        let kind = if has(&["invalid dispatcher"]) || (has(&["dispatcher"]) && has(&not_found)) {
+            CommandErrorKind::InvalidDispatcher
+        } else if has(&["invalid keyword", "unknown keyword"])
+            || (has(&["config option", "keyword"]) && has(&not_found))
+        {
+            CommandErrorKind::InvalidKeyword
+        } else if has(&["window"]) && has(&not_found) {
+            CommandErrorKind::WindowNotFound
+        } else if has(&["permission", "denied", "locked", "not allowed"]) {
+            CommandErrorKind::Locked
+        } else if has(&["invalid", "missing", "bad", "expected", "error"]) {
+            CommandErrorKind::InvalidArgument
+        } else {
+            CommandErrorKind::Other
+        };
+        Self {
+            kind,
+            raw: raw.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+/// This struct holds the differences found between the json Hyprland sent,
+/// and the structs of this crate
+///
+/// Field paths are dot separated, with `[]` marking array elements (eg. `[].activeWorkspace.id`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaReport {
+    /// The command that was queried
+    pub command: String,
+    /// Fields sent by Hyprland that this crate doesn't know about
+    pub unknown_fields: Vec<String>,
+    /// Fields this crate expects, that Hyprland didn't send
+    pub missing_fields: Vec<String>,
+    /// The raw json sent by Hyprland
+    pub raw: String,
+}
+
+impl SchemaReport {
+    /// This method returns true if any unknown or missing fields were found
+    pub fn has_drift(&self) -> bool {
+        !self.unknown_fields.is_empty() || !self.missing_fields.is_empty()
+    }
+}
+
+impl std::fmt::Display for SchemaReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` has unknown fields [{}] and missing fields [{}]",
+            self.command,
+            self.unknown_fields.join(", "),
+            self.missing_fields.join(", ")
+        )
+    }
+}
+
 /// This type provides the result type used everywhere in Hyprland-rs
 //pub type HResult<T> = Result<T, Box<dyn std::error::Error>>;
 pub type HResult<T> = Result<T, HyprError>;
 
 /// The address struct holds a address as a tuple with a single value
 /// and has methods to reveal the address in different data formats
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct Address(String);
+///
+/// Addresses are validated when parsed, and are (de)serialized as hex strings (`0x...`)
+#[derive(
+    Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct Address(u64);
 
 /// This trait provides a standardized way to get data
 #[async_trait]
@@ -142,40 +282,622 @@ pub enum WorkspaceType {
 //     }
 // }
 
+/// The id Hyprland gives to the special workspace
+pub(crate) const SPECIAL_WORKSPACE_ID: WorkspaceId = -99;
+
 impl From<i32> for WorkspaceType {
     fn from(int: i32) -> Self {
         match int {
-            1.. => WorkspaceType::Regular(int.to_string()),
-            _ => panic!("Unrecognised id"),
+            SPECIAL_WORKSPACE_ID => WorkspaceType::Special(None),
+            _ => WorkspaceType::Regular(int.to_string()),
+        }
+    }
+}
+
+/// This enum is a uniform reference to a workspace, be it numbered, named or special
+///
+/// It can be parsed from (and displayed as) the strings Hyprland uses for workspaces,
+/// for example `3`, `name:code` and `special:term`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WorkspaceRef {
+    /// A workspace identified by its id
+    Id(WorkspaceId),
+    /// A named workspace
+    Name(String),
+    /// A special workspace
+    Special(
+        /// The name, if exists
+        Option<String>,
+    ),
+}
+
+impl WorkspaceRef {
+    /// This creates a workspace reference from the id and name Hyprland reports
+    pub fn from_parts<T: AsRef<str>>(id: WorkspaceId, name: T) -> Self {
+        let name = name.as_ref();
+        if name == "special" {
+            Self::Special(None)
+        } else if let Some(special) = name.strip_prefix("special:") {
+            Self::Special(Some(special.to_string()))
+        } else if id == SPECIAL_WORKSPACE_ID {
+            Self::Special(None)
+        } else if name.is_empty() || name == id.to_string() {
+            Self::Id(id)
+        } else {
+            Self::Name(name.to_string())
+        }
+    }
+    /// This method returns true if the reference points to a special workspace
+    pub fn is_special(&self) -> bool {
+        matches!(self, Self::Special(_))
+    }
+}
+
+impl From<WorkspaceId> for WorkspaceRef {
+    fn from(id: WorkspaceId) -> Self {
+        match id {
+            SPECIAL_WORKSPACE_ID => Self::Special(None),
+            _ => Self::Id(id),
+        }
+    }
+}
+
+impl From<WorkspaceType> for WorkspaceRef {
+    fn from(work: WorkspaceType) -> Self {
+        match work {
+            WorkspaceType::Regular(name) => match name.parse::<WorkspaceId>() {
+                Ok(id) => Self::Id(id),
+                Err(_) => Self::Name(name),
+            },
+            WorkspaceType::Special(name) => Self::Special(name),
+        }
+    }
+}
+
+impl std::str::FromStr for WorkspaceRef {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(HyprError::ParseError(
+                "A workspace reference can't be empty".to_string(),
+            ));
+        }
+        Ok(if s == "special" {
+            Self::Special(None)
+        } else if let Some(name) = s.strip_prefix("special:") {
+            Self::Special(Some(name.to_string()))
+        } else if let Some(name) = s.strip_prefix("name:") {
+            Self::Name(name.to_string())
+        } else {
+            match s.parse::<WorkspaceId>() {
+                Ok(id) => Self::from(id),
+                Err(_) => Self::Name(s.to_string()),
+            }
+        })
+    }
+}
+
+impl fmt::Display for WorkspaceRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "{id}"),
+            Self::Name(name) => write!(f, "name:{name}"),
+            Self::Special(Some(name)) => write!(f, "special:{name}"),
+            Self::Special(None) => write!(f, "special"),
+        }
+    }
+}
+
+/// This type provides the id used to identify monitors
+pub type MonitorId = u8;
+
+/// This enum is a reference to a monitor
+///
+/// Data structs identify monitors by id (eg. [`Client`](crate::data::Client)),
+/// while events identify them by name, this type can hold either
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MonitorRef {
+    /// A monitor identified by its id
+    Id(MonitorId),
+    /// A monitor identified by its name (eg. `DP-1`)
+    Name(String),
+}
+
+impl From<MonitorId> for MonitorRef {
+    fn from(id: MonitorId) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<String> for MonitorRef {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<&str> for MonitorRef {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl fmt::Display for MonitorRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "{id}"),
+            Self::Name(name) => write!(f, "{name}"),
         }
     }
 }
 
+/// This struct holds a rectangle in the global layout (in logical pixels)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Geometry {
+    /// The position on the x axis
+    pub x: i32,
+    /// The position on the y axis
+    pub y: i32,
+    /// The width
+    pub width: i32,
+    /// The height
+    pub height: i32,
+}
+
+impl Geometry {
+    /// Creates a rectangle from its position and size
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+    /// This method shrinks the rectangle on each side (never below a size of 0)
+    pub fn inset(self, left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self {
+            x: self.x + left,
+            y: self.y + top,
+            width: (self.width - left - right).max(0),
+            height: (self.height - top - bottom).max(0),
+        }
+    }
+    /// This method returns true if the point is inside the rectangle
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+    /// This method returns the center of the rectangle
+    pub fn center(&self) -> (i32, i32) {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+}
+
 impl fmt::Display for Address {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "0x{:x}", self.0)
+    }
+}
+
+impl std::str::FromStr for Address {
+    type Err = HyprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let hex = trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed);
+        match u64::from_str_radix(hex, 16) {
+            Ok(value) if !hex.starts_with('+') => Ok(Self(value)),
+            _ => Err(HyprError::ParseError(format!(
+                "{s:?} is not a valid hex address"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = HyprError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Address> for String {
+    fn from(addr: Address) -> Self {
+        addr.to_string()
+    }
+}
+
+impl From<u64> for Address {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Address> for u64 {
+    fn from(addr: Address) -> Self {
+        addr.0
     }
 }
 
 impl Address {
-    /// This method returns a vector of bytes
+    /// This method returns a vector of bytes (big endian, without leading zeros)
     pub fn as_vec(self) -> Vec<u8> {
-        let Address(value) = self;
-        match hex::decode(value.trim_start_matches("0x")) {
-            Ok(value) => value,
-            Err(error) => panic!("A error has occured while parsing string as hex: {error}"),
+        let bytes = self.0.to_be_bytes();
+        let start = bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(bytes.len() - 1);
+        bytes[start..].to_vec()
+    }
+    /// This method returns the numeric value of the address
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+    /// This creates a new address from its numeric value
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+    /// This parses a address from a hex string (with or without the `0x` prefix)
+    pub fn parse<T: AsRef<str>>(string: T) -> HResult<Self> {
+        string.as_ref().parse()
+    }
+}
+
+#[test]
+fn test_address_parsing() {
+    use std::str::FromStr;
+    let addr = match Address::from_str("0x55d6ab6a4b50") {
+        Ok(addr) => addr,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(addr.as_u64(), 0x55d6ab6a4b50);
+    assert_eq!(addr.to_string(), "0x55d6ab6a4b50");
+    assert_eq!(addr.as_vec(), vec![0x55, 0xd6, 0xab, 0x6a, 0x4b, 0x50]);
+    assert!(matches!(Address::parse("55d6ab6a4b50"), Ok(a) if a == addr));
+    assert!(Address::parse("0xnothex").is_err());
+    assert!(Address::parse("").is_err());
+    assert!(Address::parse("+1f").is_err());
+}
+
+#[test]
+fn test_version_capabilities() {
+    let version = match "v0.36.0-73-gabc1234".parse::<HyprlandVersion>() {
+        Ok(ver) => ver,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    assert_eq!(version, HyprlandVersion::new(0, 36, 0));
+    assert!(version < HyprlandVersion::new(0, 40, 0));
+    assert!("main".parse::<HyprlandVersion>().is_err());
+    let caps = Capabilities::for_version(Some(version));
+    assert!(caps.active_workspace_query && caps.getoption_set);
+    assert!(!caps.window_tags && !caps.fullscreen_state);
+    assert!(Capabilities::for_version(None).fullscreen_state);
+    // nothing changes silently until the version is detected
+    assert!(!Capabilities::default().fullscreen_state);
+    assert!(!Capabilities::current().window_tags);
+}
+
+#[test]
+fn test_workspace_ref() {
+    assert_eq!(WorkspaceRef::from_parts(3, "3"), WorkspaceRef::Id(3));
+    assert_eq!(
+        WorkspaceRef::from_parts(-1337, "code"),
+        WorkspaceRef::Name("code".to_string())
+    );
+    assert_eq!(
+        WorkspaceRef::from_parts(-98, "special:term"),
+        WorkspaceRef::Special(Some("term".to_string()))
+    );
+    assert_eq!(WorkspaceRef::from(-99), WorkspaceRef::Special(None));
+    assert_eq!(WorkspaceType::from(-99), WorkspaceType::Special(None));
+    for string in ["4", "name:code", "special", "special:term"] {
+        match string.parse::<WorkspaceRef>() {
+            Ok(work) => assert_eq!(work.to_string(), string),
+            Err(e) => panic!("Error occured: {e}"),
+        }
+    }
+}
+
+/// This struct holds a Hyprland release version (eg. `v0.34.0`)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HyprlandVersion {
+    /// The major version
+    pub major: u16,
+    /// The minor version
+    pub minor: u16,
+    /// The patch version
+    pub patch: u16,
+}
+
+impl HyprlandVersion {
+    /// This creates a new version
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl std::str::FromStr for HyprlandVersion {
+    type Err = HyprError;
+
+    /// Parses a version tag, anything after the patch number (eg. `-73-gabc123`) is ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || HyprError::ParseError(format!("{s:?} is not a Hyprland version"));
+        let trimmed = s.trim().trim_start_matches('v');
+        let mut parts = trimmed.splitn(3, '.');
+        let mut next_num = |last: bool| -> Result<u16, HyprError> {
+            let part = parts.next().ok_or_else(err)?;
+            let digits = if last {
+                part.split(|c: char| !c.is_ascii_digit())
+                    .next()
+                    .unwrap_or_default()
+            } else {
+                part
+            };
+            digits.parse().map_err(|_| err())
+        };
+        Ok(Self {
+            major: next_num(false)?,
+            minor: next_num(false)?,
+            patch: next_num(true)?,
+        })
+    }
+}
+
+impl fmt::Display for HyprlandVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// This struct holds what the running Hyprland instance supports
+///
+/// Use `Capabilities::detect` (in the `data` module) once at startup, after which
+/// [Capabilities::current] is used by this crate to pick the right wire format.
+/// Until then, the [baseline](Capabilities::baseline) capabilities are used.
+/// If a detected version is unknown (eg. a build without a tag), the latest Hyprland is assumed
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The detected version, [None] if unknown (eg. a build without a tag)
+    pub version: Option<HyprlandVersion>,
+    /// The `j/activeworkspace` query is available (`>= v0.25.0`)
+    pub active_workspace_query: bool,
+    /// `getoption` returns if the option was `set` (`>= v0.27.0`)
+    pub getoption_set: bool,
+    /// `getoption` only returns the field of the option's type, instead of all of them
+    /// with sentinels for the unused ones (`>= v0.38.0`)
+    pub getoption_custom: bool,
+    /// The `tagwindow` dispatcher is available (`>= v0.37.0`)
+    pub window_tags: bool,
+    /// The `fullscreenstate` dispatcher replaces `fakefullscreen` (`>= v0.42.0`)
+    pub fullscreen_state: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::baseline()
+    }
+}
+
+lazy_static! {
+    static ref CURRENT_CAPABILITIES: std::sync::RwLock<Option<Capabilities>> =
+        std::sync::RwLock::new(None);
+}
+
+impl Capabilities {
+    /// This creates the capabilities of a Hyprland version ([None] meaning the latest)
+    pub fn for_version(version: Option<HyprlandVersion>) -> Self {
+        let since = |major, minor, patch| match version {
+            Some(ver) => ver >= HyprlandVersion::new(major, minor, patch),
+            None => true,
+        };
+        Self {
+            version,
+            active_workspace_query: since(0, 25, 0),
+            getoption_set: since(0, 27, 0),
+            getoption_custom: since(0, 38, 0),
+            window_tags: since(0, 37, 0),
+            fullscreen_state: since(0, 42, 0),
+        }
+    }
+    /// This creates the capabilities every supported Hyprland version has,
+    /// used when none were detected
+    pub fn baseline() -> Self {
+        Self {
+            version: None,
+            active_workspace_query: false,
+            getoption_set: false,
+            getoption_custom: false,
+            window_tags: false,
+            fullscreen_state: false,
+        }
+    }
+    /// This function returns the capabilities used by this crate,
+    /// the [baseline](Capabilities::baseline) ones if none were detected
+    pub fn current() -> Self {
+        match CURRENT_CAPABILITIES.read() {
+            Ok(caps) => caps.unwrap_or_default(),
+            Err(poisoned) => poisoned.into_inner().unwrap_or_default(),
+        }
+    }
+    /// This function sets the capabilities used by this crate
+    pub fn set_current(caps: Self) {
+        match CURRENT_CAPABILITIES.write() {
+            Ok(mut current) => *current = Some(caps),
+            Err(poisoned) => *poisoned.into_inner() = Some(caps),
+        }
+    }
+}
+
+/// This pub(crate) function locks shared state, a panic while it was held doesn't lose it
+pub(crate) fn lock_state<T>(state: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    match state.lock() {
+        Ok(state) => state,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+#[derive(Debug, Default)]
+struct RecorderState {
+    commands: Vec<String>,
+    responses: std::collections::VecDeque<String>,
+}
+
+type RecorderHandle = std::sync::Arc<std::sync::Mutex<RecorderState>>;
+
+thread_local! {
+    static RECORDERS: std::cell::RefCell<Vec<RecorderHandle>> =
+        const { std::cell::RefCell::new(vec![]) };
+}
+
+/// Makes a recorder active on the current thread, until it is dropped
+#[derive(Debug)]
+struct ActiveRecorder(RecorderHandle);
+
+impl ActiveRecorder {
+    fn push(state: RecorderHandle) -> Self {
+        RECORDERS.with(|recorders| recorders.borrow_mut().push(state.clone()));
+        Self(state)
+    }
+}
+
+impl Drop for ActiveRecorder {
+    fn drop(&mut self) {
+        let _ = RECORDERS.try_with(|recorders| {
+            let mut recorders = recorders.borrow_mut();
+            if let Some(pos) = recorders
+                .iter()
+                .rposition(|state| std::sync::Arc::ptr_eq(state, &self.0))
+            {
+                recorders.remove(pos);
+            }
+        });
+    }
+}
+
+/// A dry-run scope for the command socket
+///
+/// While a recorder is alive, everything this crate would write to Hyprland's command socket
+/// on the current thread (dispatchers, keywords, binds, queries) is appended to its log
+/// instead, and answered with a scripted response (`ok` by default).
+/// Recorders nest, only the most recently started one records, and recorders on other
+/// threads (like parallel tests) never see each other's commands.
+///
+/// Async tasks can be moved between the threads of a runtime, so a future is recorded
+/// wherever it runs by wrapping it with [Recorder::scope].
+///
+/// ```rust
+/// # use hyprland::shared::HResult;
+/// # fn main() -> HResult<()> {
+/// use hyprland::dispatch::{Dispatch, DispatchType};
+/// use hyprland::shared::Recorder;
+/// let recorder = Recorder::start();
+/// Dispatch::call(DispatchType::Exec("kitty"))?;
+/// assert_eq!(recorder.commands(), vec!["dispatch exec kitty"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Recorder {
+    state: RecorderHandle,
+    _active: ActiveRecorder,
+    // it is only active on the thread it was started on, so it can't be sent to another one
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl Recorder {
+    /// Starts recording on the current thread, until the returned recorder is dropped
+    pub fn start() -> Self {
+        let state = RecorderHandle::default();
+        Self {
+            _active: ActiveRecorder::push(state.clone()),
+            state,
+            _not_send: std::marker::PhantomData,
+        }
+    }
+    /// Records the commands of a future, on whichever thread it is polled
+    ///
+    /// The returned future can be spawned on a multi-threaded runtime
+    pub fn scope<F: std::future::Future>(&self, future: F) -> RecorderScope<F> {
+        RecorderScope {
+            state: self.state.clone(),
+            future: Box::pin(future),
         }
     }
-    /// This creates a new address from a value that implements [std::string::ToString]
-    pub fn new<T: ToString>(string: T) -> Self {
-        Self(string.to_string())
+    fn with_state<T>(&self, f: impl FnOnce(&mut RecorderState) -> T) -> T {
+        f(&mut lock_state(&self.state))
+    }
+    /// Queues a response for the next command, once the queue is empty `ok` is returned
+    pub fn respond_with<T: ToString>(&self, response: T) -> &Self {
+        self.with_state(|state| state.responses.push_back(response.to_string()));
+        self
+    }
+    /// Returns the commands recorded so far, as they would have been sent
+    pub fn commands(&self) -> Vec<String> {
+        self.with_state(|state| state.commands.clone())
+    }
+    /// Clears the recorded commands
+    pub fn clear(&self) {
+        self.with_state(|state| state.commands.clear());
+    }
+}
+
+/// A future that is recorded by a [Recorder], created with [Recorder::scope]
+#[derive(Debug)]
+pub struct RecorderScope<F> {
+    state: RecorderHandle,
+    future: std::pin::Pin<Box<F>>,
+}
+
+impl<F: std::future::Future> std::future::Future for RecorderScope<F> {
+    type Output = F::Output;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let _active = ActiveRecorder::push(self.state.clone());
+        self.future.as_mut().poll(cx)
     }
 }
 
+fn recording() -> bool {
+    RECORDERS
+        .try_with(|recorders| !recorders.borrow().is_empty())
+        .unwrap_or(false)
+}
+
+/// Records a command if a [Recorder] is active, returning its scripted response
+fn record(content: &[u8]) -> Option<String> {
+    let state = RECORDERS
+        .try_with(|recorders| recorders.borrow().last().cloned())
+        .ok()??;
+    let mut state = lock_state(&state);
+    state
+        .commands
+        .push(String::from_utf8_lossy(content).into_owned());
+    Some(
+        state
+            .responses
+            .pop_front()
+            .unwrap_or_else(|| "ok".to_string()),
+    )
+}
+
 /// This pub(crate) function is used to write a value to a socket and to get the response
 pub(crate) async fn write_to_socket(path: String, content: &[u8]) -> HResult<String> {
     use crate::unix_async::*;
 
+    if let Some(response) = record(content) {
+        return Ok(response);
+    }
     let mut stream = UnixStream::connect(path).await?;
 
     stream.write_all(content).await?;
@@ -189,6 +911,9 @@ pub(crate) async fn write_to_socket(path: String, content: &[u8]) -> HResult<Str
 pub(crate) fn write_to_socket_sync(path: String, content: &[u8]) -> HResult<String> {
     use io::prelude::*;
     use std::os::unix::net::UnixStream;
+    if let Some(response) = record(content) {
+        return Ok(response);
+    }
     let mut stream = UnixStream::connect(path)?;
 
     stream.write_all(content)?;
@@ -209,6 +934,8 @@ pub(crate) enum SocketType {
 pub(crate) fn get_socket_path(socket_type: SocketType) -> String {
     let hypr_instance_sig = match var("HYPRLAND_INSTANCE_SIGNATURE") {
         Ok(var) => var,
+        // the path isn't used while recording, so it works without Hyprland
+        Err(VarError::NotPresent) if recording() => String::new(),
         Err(VarError::NotPresent) => panic!("Is hyprland running?"),
         Err(VarError::NotUnicode(_)) => panic!("wtf no unicode?"),
     };
@@ -233,9 +960,9 @@ where
     #[derive(Deserialize, Debug)]
     #[serde(untagged)]
     enum Aux<T> {
-        T(T),
         Empty(Empty),
         Null,
+        T(T),
     }
 
     match Deserialize::deserialize(deserializer)? {
@@ -243,3 +970,107 @@ where
         Aux::Empty(_) | Aux::Null => Ok(None),
     }
 }
+
+#[test]
+fn test_recorder() {
+    let send = |content: &str| match write_to_socket_sync(String::new(), content.as_bytes()) {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let outer = Recorder::start();
+    outer.respond_with("error");
+    assert_eq!(send("dispatch exec kitty"), "error");
+    assert_eq!(send("keyword general:gaps_in 5"), "ok");
+    {
+        let inner = Recorder::start();
+        send("j/clients");
+        assert_eq!(inner.commands(), vec!["j/clients"]);
+    }
+    assert_eq!(
+        outer.commands(),
+        vec!["dispatch exec kitty", "keyword general:gaps_in 5"]
+    );
+    outer.clear();
+    assert!(outer.commands().is_empty());
+    drop(outer);
+    assert!(!recording());
+    // a recorder on another thread doesn't see this thread's commands, and doesn't wait for it
+    let local = Recorder::start();
+    let other = std::thread::spawn(move || {
+        let recorder = Recorder::start();
+        send("dispatch workspace 2");
+        recorder.commands()
+    });
+    send("dispatch workspace 1");
+    match other.join() {
+        Ok(commands) => assert_eq!(commands, vec!["dispatch workspace 2"]),
+        Err(_) => panic!("The recording thread panicked"),
+    }
+    assert_eq!(local.commands(), vec!["dispatch workspace 1"]);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_recorder_scope_across_threads() {
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .build()
+    {
+        Ok(v) => v,
+        Err(e) => panic!("Error occured: {e}"),
+    };
+    let recorder = Recorder::start();
+    recorder.respond_with("scripted");
+    let tasks: Vec<_> = (0..8)
+        .map(|i| {
+            runtime.spawn(recorder.scope(async move {
+                tokio::task::yield_now().await;
+                write_to_socket(String::new(), format!("dispatch workspace {i}").as_bytes()).await
+            }))
+        })
+        .collect();
+    let responses: Vec<String> = runtime.block_on(async {
+        let mut responses = vec![];
+        for task in tasks {
+            match task.await {
+                Ok(Ok(response)) => responses.push(response),
+                Ok(Err(e)) => panic!("Error occured: {e}"),
+                Err(e) => panic!("Error occured: {e}"),
+            }
+        }
+        responses
+    });
+    assert_eq!(
+        responses.iter().filter(|resp| *resp == "scripted").count(),
+        1
+    );
+    let commands = recorder.commands();
+    assert_eq!(commands.len(), 8);
+    for i in 0..8 {
+        assert!(commands.contains(&format!("dispatch workspace {i}")));
+    }
+}
+
+#[test]
+fn test_command_errors() {
+    use CommandErrorKind::*;
+    for (raw, kind) in [
+        ("Invalid dispatcher", InvalidDispatcher),
+        (
+            "Invalid dispatcher, requested \"foo\" does not exist",
+            InvalidDispatcher,
+        ),
+        ("No such window found", WindowNotFound),
+        (
+            "config option <general:foo> does not exist.",
+            InvalidKeyword,
+        ),
+        ("Invalid arg in movetoworkspace", InvalidArgument),
+        ("Permission denied", Locked),
+        ("something went wrong", Other),
+    ] {
+        let err = CommandError::parse(raw);
+        assert_eq!(err.kind, kind, "{raw}");
+        assert_eq!(err.raw, raw);
+    }
+}